@@ -1,9 +1,33 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::AuctionError;
+use crate::pricing;
 
 use std::mem::size_of;
 
+/**
+ * Protocol
+ * Program wide settings, a single PDA initialized by the admin right after deployment.
+ */
+#[account]
+pub struct BoyncConfig {
+    pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub fee_bps: u16, // protocol fee taken out of every sale
+    pub bump: u8,
+}
+
+impl BoyncConfig {
+    pub const ACCOUNT_SIZE: usize = size_of::<BoyncConfig>();
+
+    /// Splits a sale into (seller share, protocol fee).
+    pub fn split_fee(&self, amount: u64) -> Result<(u64, u64)> {
+        let (fee, seller_share) = pricing::split_bps(amount, self.fee_bps)?;
+
+        Ok((seller_share, fee))
+    }
+}
+
 /**
  * [Deprecated]
  */
@@ -54,6 +78,16 @@ pub struct BoyncAuction2 {
     pub state: AuctionState, // 1 + 32
     pub last_bidder: Pubkey,
     pub bump: u8,
+    pub highest_bid: u64, // last bid paid into the bidders_chest
+    pub reserve_price: Option<u64>,
+    pub buy_now_price: Option<u64>,
+    pub time_extension: TimeExtensionPolicy,
+    pub extended_by: i64, // total ms added to end_auction_at by bids
+    pub bid_increment: BidIncrement,
+    pub crank_fee_bps: u16, // share of the proceeds paid to whoever calls `settle`
+    pub seller_splits: SellerSplits,
+    pub proceeds: Option<ProceedsPayout>, // set once the bidders_chest was paid out by `end` or `settle`
+    pub bid_gate: BidGate,
 }
 
 /**
@@ -68,6 +102,94 @@ impl BoyncAuction2 {
     pub fn ended(&self, now: i64) -> Result<bool> {
         Ok(now * MS_IN_SEC > self.end_auction_at)
     }
+
+    /// No reserve price means any final bid is accepted.
+    pub fn reserve_met(&self) -> bool {
+        match self.reserve_price {
+            Some(reserve_price) => self.highest_bid >= reserve_price,
+            None => true,
+        }
+    }
+
+    /// Buy now is offered until bidding reaches the buy now price.
+    pub fn buy_now_available(&self) -> bool {
+        match self.buy_now_price {
+            Some(buy_now_price) => self.highest_bid < buy_now_price,
+            None => false,
+        }
+    }
+
+    pub fn proceeds_paid(&self) -> bool {
+        self.proceeds.is_some()
+    }
+
+    /// Token claimed and nothing left to pay out or refund.
+    /// `chest_balance` is what the bidders chest still holds, bids are refunded from it when the reserve wasn't met.
+    pub fn settled(&self, chest_balance: u64) -> bool {
+        let refunded = !self.reserve_met() && chest_balance == 0;
+        self.claimed == 1 && (self.last_bidder == Pubkey::default() || self.proceeds_paid() || refunded)
+    }
+}
+
+/**
+ * V2
+ * Layout of auctions created before reserve prices, read once by `migrate_auction2`.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BoyncAuction2Legacy {
+    pub id: i64,
+    pub start_auction_at: i64, // 1 + 64
+    pub end_auction_at: i64, // 1 + 64
+    pub authority: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub bidders_chest: Pubkey,
+    pub starting_price: u64,
+    pub next_bid: u64,
+    pub claimed: u8,
+    pub state: AuctionState, // 1 + 32
+    pub last_bidder: Pubkey,
+    pub bump: u8,
+}
+
+impl BoyncAuction2Legacy {
+    pub const AUCTION_SIZE: usize = size_of::<BoyncAuction2Legacy>();
+
+    /// Current layout, new fields keep the behaviour the auction was created with.
+    pub fn migrate(&self) -> Result<BoyncAuction2> {
+        // Legacy auctions didn't record the last bid, it is the 5% step below `next_bid`.
+        let highest_bid = if self.last_bidder == Pubkey::default() {
+            0
+        } else {
+            pricing::reverse_increase_by_bps(self.next_bid, BID_INCREMENT_BPS)?
+        };
+
+        Ok(BoyncAuction2 {
+            id: self.id,
+            start_auction_at: self.start_auction_at,
+            end_auction_at: self.end_auction_at,
+            authority: self.authority,
+            treasury_mint: self.treasury_mint,
+            treasury: self.treasury,
+            bidders_chest: self.bidders_chest,
+            starting_price: self.starting_price,
+            next_bid: self.next_bid,
+            claimed: self.claimed,
+            state: self.state,
+            last_bidder: self.last_bidder,
+            bump: self.bump,
+            highest_bid,
+            reserve_price: None,
+            buy_now_price: None,
+            time_extension: TimeExtensionPolicy::LEGACY,
+            extended_by: 0,
+            bid_increment: BidIncrement::LEGACY_V2,
+            crank_fee_bps: 0,
+            seller_splits: SellerSplits::default(),
+            proceeds: None,
+            bid_gate: BidGate::Open,
+        })
+    }
 }
 
 /**
@@ -88,6 +210,22 @@ pub struct BoyncAuction3 {
     pub state: AuctionState, // 1 + 32
     pub last_bidder: Pubkey,
     pub bump: u8,
+    pub reserve_price: Option<u64>,
+    pub buy_now_price: Option<u64>,
+    pub time_extension: TimeExtensionPolicy,
+    pub extended_by: i64, // total ms added to end_auction_at by bids
+    pub bid_increment: BidIncrement,
+    pub bid_token_cost: u64, // chest tokens charged for a bid
+    pub bid_cost_rise: Option<BidCostRise>,
+    pub bid_count: u64,
+    pub seller_splits: SellerSplits,
+    pub payment_window: i64, // ms the winner has to pay after the auction ends
+    pub payment_due_at: i64, // 0 until the auction is offered to the runner-up
+    pub runner_up: Pubkey, // last outbid wallet
+    pub runner_up_bid: u64, // runner-up's last price
+    pub bid_deposit: u64, // lamports each bidder locks on their first bid
+    pub open_deposits: u64, // deposits not refunded nor slashed yet
+    pub bid_gate: BidGate,
 }
 
 /**
@@ -101,6 +239,265 @@ impl BoyncAuction3 {
     pub fn ended(&self, now: i64) -> Result<bool> {
         Ok(now * MS_IN_SEC > self.end_auction_at)
     }
+
+    /// No reserve price means any final bid is accepted.
+    pub fn reserve_met(&self) -> bool {
+        match self.reserve_price {
+            Some(reserve_price) => self.current_bid >= reserve_price,
+            None => true,
+        }
+    }
+
+    /// Buy now is offered until bidding reaches the buy now price.
+    pub fn buy_now_available(&self) -> bool {
+        match self.buy_now_price {
+            Some(buy_now_price) => self.current_bid < buy_now_price,
+            None => false,
+        }
+    }
+
+    /// Chest tokens charged for the next bid.
+    pub fn next_bid_token_cost(&self) -> Result<u64> {
+        match self.bid_cost_rise {
+            Some(bid_cost_rise) => bid_cost_rise.cost(self.bid_token_cost, self.bid_count),
+            None => Ok(self.bid_token_cost),
+        }
+    }
+
+    /// Winner has to pay by then, afterwards the authority can reclaim the token or offer it to the runner-up.
+    pub fn payment_deadline(&self) -> Result<i64> {
+        if self.payment_due_at > 0 {
+            return Ok(self.payment_due_at);
+        }
+
+        self.end_auction_at
+            .checked_add(self.payment_window)
+            .ok_or_else(|| AuctionError::NumericalOverflow.into())
+    }
+
+    pub fn has_runner_up(&self) -> bool {
+        self.runner_up != Pubkey::default()
+    }
+}
+
+/**
+ * V3
+ * Layout of auctions created before reserve prices, read once by `migrate_auction3`.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BoyncAuction3Legacy {
+    pub id: i64,
+    pub start_auction_at: i64, // 1 + 64
+    pub end_auction_at: i64, // 1 + 64
+    pub authority: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub chest: Pubkey,
+    pub current_bid: u64,
+    pub claimed: u8,
+    pub state: AuctionState, // 1 + 32
+    pub last_bidder: Pubkey,
+    pub bump: u8,
+}
+
+impl BoyncAuction3Legacy {
+    pub const AUCTION_SIZE: usize = size_of::<BoyncAuction3Legacy>();
+
+    /// Current layout, new fields keep the behaviour the auction was created with.
+    pub fn migrate(&self) -> BoyncAuction3 {
+        BoyncAuction3 {
+            id: self.id,
+            start_auction_at: self.start_auction_at,
+            end_auction_at: self.end_auction_at,
+            authority: self.authority,
+            treasury_mint: self.treasury_mint,
+            treasury: self.treasury,
+            chest: self.chest,
+            current_bid: self.current_bid,
+            claimed: self.claimed,
+            state: self.state,
+            last_bidder: self.last_bidder,
+            bump: self.bump,
+            reserve_price: None,
+            buy_now_price: None,
+            time_extension: TimeExtensionPolicy::LEGACY,
+            extended_by: 0,
+            bid_increment: BidIncrement::LEGACY_V3,
+            bid_token_cost: V3_BID_TOKEN_COST,
+            bid_cost_rise: None,
+            bid_count: 0,
+            seller_splits: SellerSplits::default(),
+            payment_window: V3_PAYMENT_WINDOW,
+            payment_due_at: 0,
+            runner_up: Pubkey::default(),
+            runner_up_bid: 0,
+            bid_deposit: 0,
+            open_deposits: 0,
+            bid_gate: BidGate::Open,
+        }
+    }
+}
+
+/**
+ * English
+ * Users bid any amount of SOL above the minimum next bid.
+ * Only the highest bid is escrowed in the state PDA, outbid users are refunded.
+ */
+#[account]
+pub struct BoyncEnglishAuction {
+    pub id: i64,
+    pub start_auction_at: i64, // 1 + 64
+    pub end_auction_at: i64, // 1 + 64
+    pub authority: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub starting_price: u64,
+    pub min_bid_increment: u64,
+    pub highest_bid: u64,
+    pub claimed: u8,
+    pub state: AuctionState, // 1 + 32
+    pub last_bidder: Pubkey,
+    pub bump: u8,
+    pub buy_now_price: Option<u64>,
+    pub time_extension: TimeExtensionPolicy,
+    pub extended_by: i64, // total ms added to end_auction_at by bids
+    pub seller_splits: SellerSplits,
+    pub proxy_max: u64, // leader's proxy maximum, 0 when the leader bid manually
+    pub bid_gate: BidGate,
+}
+
+/**
+ * English
+ * Users bid any amount of SOL above the minimum next bid.
+ */
+impl BoyncEnglishAuction {
+    pub const AUCTION_SIZE: usize = size_of::<BoyncEnglishAuction>();
+
+    pub fn ended(&self, now: i64) -> Result<bool> {
+        Ok(now * MS_IN_SEC > self.end_auction_at)
+    }
+
+    pub fn has_bids(&self) -> bool {
+        self.last_bidder != Pubkey::default()
+    }
+
+    /// Smallest amount the next bid has to match.
+    pub fn min_next_bid(&self) -> Result<u64> {
+        if !self.has_bids() {
+            return Ok(self.starting_price);
+        }
+
+        pricing::checked_add(self.highest_bid, self.min_bid_increment)
+    }
+
+    /// Buy now is offered until bidding reaches the buy now price.
+    pub fn buy_now_available(&self) -> bool {
+        match self.buy_now_price {
+            Some(buy_now_price) => self.highest_bid < buy_now_price,
+            None => false,
+        }
+    }
+}
+
+/**
+ * Dutch
+ * Price falls linearly from `start_price` to `floor_price` between start and end,
+ * first user to accept the current price buys the token.
+ */
+#[account]
+pub struct BoyncDutchAuction {
+    pub id: i64,
+    pub start_auction_at: i64, // 1 + 64
+    pub end_auction_at: i64, // 1 + 64
+    pub authority: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub sold_price: u64,
+    pub claimed: u8,
+    pub state: AuctionState, // 1 + 32
+    pub last_bidder: Pubkey,
+    pub bump: u8,
+    pub seller_splits: SellerSplits,
+    pub bid_gate: BidGate,
+}
+
+/**
+ * Dutch
+ * Price falls linearly from `start_price` to `floor_price` between start and end.
+ */
+impl BoyncDutchAuction {
+    pub const AUCTION_SIZE: usize = size_of::<BoyncDutchAuction>();
+
+    pub fn ended(&self, now: i64) -> Result<bool> {
+        Ok(now * MS_IN_SEC > self.end_auction_at)
+    }
+
+    /// Price at `current_timestamp` (ms), clamped to [floor_price, start_price].
+    pub fn current_price(&self, current_timestamp: i64) -> Result<u64> {
+        if current_timestamp <= self.start_auction_at {
+            return Ok(self.start_price);
+        }
+        if current_timestamp >= self.end_auction_at {
+            return Ok(self.floor_price);
+        }
+
+        pricing::interpolate_price(
+            self.start_price,
+            self.floor_price,
+            (current_timestamp - self.start_auction_at) as u64,
+            (self.end_auction_at - self.start_auction_at) as u64,
+        )
+    }
+}
+
+/**
+ * Sealed
+ * Users commit hash(amount, salt) with a deposit, then reveal once the commit window is over.
+ * Highest revealed bid wins and pays the second highest revealed bid (Vickrey).
+ */
+#[account]
+pub struct BoyncSealedAuction {
+    pub id: i64,
+    pub start_auction_at: i64, // 1 + 64
+    pub end_auction_at: i64, // 1 + 64, end of the commit window
+    pub reveal_end_at: i64, // 1 + 64
+    pub authority: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub starting_price: u64,
+    pub min_deposit: u64,
+    pub forfeit_bps: u16, // share of an unrevealed deposit forfeited to the authority
+    pub highest_bid: u64,
+    pub second_bid: u64,
+    pub claimed: u8,
+    pub state: AuctionState, // 1 + 32
+    pub last_bidder: Pubkey,
+    pub bump: u8,
+    pub seller_splits: SellerSplits,
+    pub bid_gate: BidGate,
+}
+
+/**
+ * Sealed
+ * Users commit hash(amount, salt) with a deposit, then reveal once the commit window is over.
+ */
+impl BoyncSealedAuction {
+    pub const AUCTION_SIZE: usize = size_of::<BoyncSealedAuction>();
+
+    pub fn ended(&self, now: i64) -> Result<bool> {
+        Ok(now * MS_IN_SEC > self.reveal_end_at)
+    }
+
+    pub fn has_bids(&self) -> bool {
+        self.last_bidder != Pubkey::default()
+    }
+
+    /// Price paid by the winner: second highest revealed bid, never below the starting price.
+    pub fn clearing_price(&self) -> u64 {
+        self.second_bid.max(self.starting_price)
+    }
 }
 
 #[account]
@@ -115,6 +512,92 @@ impl BoyncUserBid {
     pub const ACCOUNT_SIZE: usize = size_of::<BoyncUserBid>();
 }
 
+/**
+ * V3
+ * Lamports a bidder locks on their first bid, refunded after settlement or slashed on default.
+ */
+#[account]
+pub struct BoyncBidDeposit {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+}
+
+impl BoyncBidDeposit {
+    pub const ACCOUNT_SIZE: usize = size_of::<BoyncBidDeposit>();
+}
+
+/**
+ * English
+ * Bidder's maximum price, the account escrows the SOL the program bids with on their behalf.
+ */
+#[account]
+pub struct BoyncProxyBid {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub max_bid: u64,
+}
+
+impl BoyncProxyBid {
+    pub const ACCOUNT_SIZE: usize = size_of::<BoyncProxyBid>();
+}
+
+/**
+ * V2 / V3
+ * Number of bids a bidder placed on an auction, bid records are keyed by it.
+ */
+#[account]
+pub struct BoyncBidderCounter {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub bid_count: u64,
+}
+
+impl BoyncBidderCounter {
+    pub const ACCOUNT_SIZE: usize = size_of::<BoyncBidderCounter>();
+
+    /// Sequence number of the bidder's next bid, starting at 1.
+    pub fn next_seq(&self) -> u64 {
+        self.bid_count + 1
+    }
+}
+
+/**
+ * V3
+ * Wallet that won an auction and didn't pay within the payment window, one record per wallet.
+ */
+#[account]
+pub struct BoyncDefaulter {
+    pub bidder: Pubkey,
+    pub default_count: u64,
+    pub unpaid_total: u64, // sum of the winning bids left unpaid
+    pub last_auction: Pubkey,
+    pub last_default_at: i64,
+}
+
+impl BoyncDefaulter {
+    pub const ACCOUNT_SIZE: usize = size_of::<BoyncDefaulter>();
+}
+
+/**
+ * Sealed
+ * One commitment per bidder, the account also escrows the bidder's deposit.
+ */
+#[account]
+pub struct BoyncSealedBid {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub commitment: [u8; 32],
+    pub deposit: u64,
+    pub bid_value: u64,
+    pub revealed: u8,
+    pub ts: i64, // 1 + 64
+}
+
+impl BoyncSealedBid {
+    pub const ACCOUNT_SIZE: usize = size_of::<BoyncSealedBid>();
+}
+
 /*
  * Boync Auction State
  *
@@ -122,8 +605,10 @@ impl BoyncUserBid {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy)]
 pub enum AuctionState {
     Created,
-    Started,
+    Active,
     Ended,
+    Settled,
+    Cancelled,
 }
 
 impl AuctionState {
@@ -134,7 +619,7 @@ impl AuctionState {
     #[inline(always)]
     pub fn start(self) -> Result<Self> {
         match self {
-            AuctionState::Created => Ok(AuctionState::Started),
+            AuctionState::Created => Ok(AuctionState::Active),
             _ => Err(AuctionError::AuctionTransitionInvalid.into()),
         }
     }
@@ -142,9 +627,714 @@ impl AuctionState {
     #[inline(always)]
     pub fn end(self) -> Result<Self> {
         match self {
-            AuctionState::Started => Ok(AuctionState::Ended),
+            AuctionState::Active => Ok(AuctionState::Ended),
             AuctionState::Created => Ok(AuctionState::Ended),
             _ => Err(AuctionError::AuctionTransitionInvalid.into()),
         }
     }
-}
\ No newline at end of file
+
+    #[inline(always)]
+    pub fn settle(self) -> Result<Self> {
+        match self {
+            AuctionState::Ended => Ok(AuctionState::Settled),
+            _ => Err(AuctionError::AuctionTransitionInvalid.into()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn cancel(self) -> Result<Self> {
+        match self {
+            AuctionState::Created => Ok(AuctionState::Cancelled),
+            AuctionState::Active => Ok(AuctionState::Cancelled),
+            _ => Err(AuctionError::AuctionTransitionInvalid.into()),
+        }
+    }
+
+    /// Ended auctions stay open for refunds and proceeds until they are settled.
+    pub fn is_over(&self) -> bool {
+        *self == AuctionState::Ended || *self == AuctionState::Settled
+    }
+}
+
+/// Auctions whose `state` follows the clock between the start and the end of their schedule.
+pub trait ScheduledAuction {
+    fn auction_state(&self) -> AuctionState;
+
+    fn set_auction_state(&mut self, state: AuctionState);
+
+    /// (start, end) in ms, sealed auctions end once the reveal window closes.
+    fn schedule(&self) -> (i64, i64);
+}
+
+impl ScheduledAuction for BoyncAuction2 {
+    fn auction_state(&self) -> AuctionState {
+        self.state
+    }
+
+    fn set_auction_state(&mut self, state: AuctionState) {
+        self.state = state;
+    }
+
+    fn schedule(&self) -> (i64, i64) {
+        (self.start_auction_at, self.end_auction_at)
+    }
+}
+
+impl ScheduledAuction for BoyncAuction3 {
+    fn auction_state(&self) -> AuctionState {
+        self.state
+    }
+
+    fn set_auction_state(&mut self, state: AuctionState) {
+        self.state = state;
+    }
+
+    fn schedule(&self) -> (i64, i64) {
+        (self.start_auction_at, self.end_auction_at)
+    }
+}
+
+impl ScheduledAuction for BoyncEnglishAuction {
+    fn auction_state(&self) -> AuctionState {
+        self.state
+    }
+
+    fn set_auction_state(&mut self, state: AuctionState) {
+        self.state = state;
+    }
+
+    fn schedule(&self) -> (i64, i64) {
+        (self.start_auction_at, self.end_auction_at)
+    }
+}
+
+impl ScheduledAuction for BoyncDutchAuction {
+    fn auction_state(&self) -> AuctionState {
+        self.state
+    }
+
+    fn set_auction_state(&mut self, state: AuctionState) {
+        self.state = state;
+    }
+
+    fn schedule(&self) -> (i64, i64) {
+        (self.start_auction_at, self.end_auction_at)
+    }
+}
+
+impl ScheduledAuction for BoyncSealedAuction {
+    fn auction_state(&self) -> AuctionState {
+        self.state
+    }
+
+    fn set_auction_state(&mut self, state: AuctionState) {
+        self.state = state;
+    }
+
+    fn schedule(&self) -> (i64, i64) {
+        (self.start_auction_at, self.reveal_end_at)
+    }
+}
+
+/*
+ * Anti-sniping policy
+ *
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy)]
+pub struct TimeExtensionPolicy {
+    /// Only bids placed in the last `window` ms extend the auction.
+    pub window: i64,
+    /// Ms added to `end_auction_at` by an extending bid.
+    pub amount: i64,
+    /// Maximum total ms an auction can be extended by.
+    pub max_extension: i64,
+}
+
+impl TimeExtensionPolicy {
+    /// Every bid adds 60 seconds, up to the longest auction duration.
+    pub const LEGACY: TimeExtensionPolicy = TimeExtensionPolicy {
+        window: i64::MAX,
+        amount: 60 * MS_IN_SEC,
+        max_extension: MAX_AUCTION_DURATION,
+    };
+
+    /// Amount and cap are bounded by the longest auction duration, extensions keep `end_auction_at` far from overflowing.
+    pub fn validate(&self) -> Result<()> {
+        if self.window < 0 || self.amount < 0 || self.max_extension < 0 {
+            return err!(AuctionError::InvalidTimeExtension);
+        }
+
+        if self.amount > MAX_AUCTION_DURATION || self.max_extension > MAX_AUCTION_DURATION {
+            return err!(AuctionError::InvalidTimeExtension);
+        }
+
+        Ok(())
+    }
+
+    /// Ms a bid placed at `current_timestamp` adds to `end_auction_at`.
+    pub fn extension(&self, current_timestamp: i64, end_auction_at: i64, extended_by: i64) -> i64 {
+        if current_timestamp > end_auction_at
+            || end_auction_at - current_timestamp > self.window
+        {
+            return 0;
+        }
+
+        let remaining = self.max_extension.saturating_sub(extended_by).max(0);
+
+        self.amount.min(remaining)
+    }
+}
+
+impl Default for TimeExtensionPolicy {
+    fn default() -> Self {
+        TimeExtensionPolicy::LEGACY
+    }
+}
+
+/*
+ * Bid increment schedule
+ *
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Default)]
+pub struct IncrementTier {
+    /// Tier applies to bids from this amount upwards.
+    pub from: u64,
+    /// Lamports added to a bid within this tier, 0 marks an unused tier.
+    pub increment: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy)]
+pub enum BidIncrement {
+    /// Every bid adds the same `amount`.
+    Fixed { amount: u64 },
+    /// Every bid adds `bps` of the current bid.
+    Percentage { bps: u16 },
+    /// Bid adds the increment of the highest tier it reached, e.g.
+    /// +0.01 SOL from 0 and +0.1 SOL from 1 SOL.
+    Tiered { tiers: [IncrementTier; MAX_INCREMENT_TIERS] },
+}
+
+impl BidIncrement {
+    /// V2: next_bid grows 5% per bid.
+    pub const LEGACY_V2: BidIncrement = BidIncrement::Percentage { bps: BID_INCREMENT_BPS };
+    /// V3: current_bid grows 0.01 SOL per bid.
+    pub const LEGACY_V3: BidIncrement = BidIncrement::Fixed { amount: V3_BID_INCREMENT };
+
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            BidIncrement::Fixed { amount } => {
+                require!(*amount > 0, AuctionError::InvalidBidIncrement);
+            }
+            BidIncrement::Percentage { bps } => {
+                require!(*bps > 0 && *bps <= MAX_BASIS_POINTS, AuctionError::InvalidBidIncrement);
+            }
+            BidIncrement::Tiered { tiers } => {
+                require!(
+                    tiers[0].from == 0 && tiers[0].increment > 0,
+                    AuctionError::InvalidBidIncrement
+                );
+
+                for pair in tiers.windows(2) {
+                    if pair[1].increment == 0 {
+                        require!(pair[1].from == 0, AuctionError::InvalidBidIncrement);
+                    } else {
+                        require!(
+                            pair[0].increment > 0 && pair[1].from > pair[0].from,
+                            AuctionError::InvalidBidIncrement
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Bid following `current_bid`, always at least one lamport higher.
+    pub fn next_bid(&self, current_bid: u64) -> Result<u64> {
+        let increment = match self {
+            BidIncrement::Fixed { amount } => *amount,
+            BidIncrement::Percentage { bps } => pricing::apply_bps(current_bid, *bps)?,
+            BidIncrement::Tiered { tiers } => tiers
+                .iter()
+                .rfind(|tier| tier.increment > 0 && tier.from <= current_bid)
+                .map(|tier| tier.increment)
+                .unwrap_or(0),
+        };
+
+        pricing::checked_add(current_bid, increment.max(1))
+    }
+}
+
+/*
+ * V3 rising bid token cost
+ *
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy)]
+pub struct BidCostRise {
+    /// Bid token cost rises after every `every_bids` bids.
+    pub every_bids: u64,
+    /// Chest tokens added to the bid token cost on each rise.
+    pub amount: u64,
+}
+
+impl BidCostRise {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.every_bids > 0 && self.amount > 0, AuctionError::InvalidBidTokenCost);
+
+        Ok(())
+    }
+
+    /// Cost of a bid once `bid_count` bids were placed, starting from `base_cost`.
+    pub fn cost(&self, base_cost: u64, bid_count: u64) -> Result<u64> {
+        let rises = bid_count / self.every_bids;
+        let rise = rises
+            .checked_mul(self.amount)
+            .ok_or(AuctionError::NumericalOverflow)?;
+
+        pricing::checked_add(base_cost, rise)
+    }
+}
+
+/*
+ * Seller revenue splits
+ *
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Default)]
+pub struct SellerSplit {
+    /// Receives `bps` of the seller proceeds.
+    pub recipient: Pubkey,
+    /// Share of the seller proceeds, 0 marks an unused split.
+    pub bps: u16,
+}
+
+/// Seller proceeds are paid out to up to `MAX_SELLER_SPLITS` recipients, e.g. co-owners,
+/// a DAO treasury or a charity. No splits means everything goes to the authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Default)]
+pub struct SellerSplits {
+    pub splits: [SellerSplit; MAX_SELLER_SPLITS],
+}
+
+impl SellerSplits {
+    pub fn is_set(&self) -> bool {
+        self.splits[0].bps > 0
+    }
+
+    /// Used splits come first, have a recipient and add up to 100%.
+    pub fn validate(&self) -> Result<()> {
+        let mut total: u16 = 0;
+        let mut unused = false;
+
+        for split in self.splits.iter() {
+            if split.bps == 0 {
+                require!(split.recipient == Pubkey::default(), AuctionError::InvalidSellerSplits);
+                unused = true;
+                continue;
+            }
+
+            require!(
+                !unused && split.recipient != Pubkey::default(),
+                AuctionError::InvalidSellerSplits
+            );
+
+            total = total
+                .checked_add(split.bps)
+                .ok_or(AuctionError::InvalidSellerSplits)?;
+        }
+
+        require!(total == 0 || total == MAX_BASIS_POINTS, AuctionError::InvalidSellerSplits);
+
+        Ok(())
+    }
+}
+
+/*
+ * Proceeds payout
+ *
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Default)]
+pub struct ProceedsPayout {
+    /// Lamports paid to the authority, or its seller splits.
+    pub seller: u64,
+    /// Lamports paid to the protocol fee recipient.
+    pub protocol_fee: u64,
+    /// Lamports paid to the verified creators.
+    pub royalties: u64,
+    /// Lamports paid to the keeper that settled the auction.
+    pub crank_fee: u64,
+}
+
+/*
+ * Bidding gate
+ *
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Copy, Default)]
+pub enum BidGate {
+    /// Anyone can bid.
+    #[default]
+    Open,
+    /// Bidder holds at least `min_amount` of `mint`.
+    Token { mint: Pubkey, min_amount: u64 },
+    /// Bidder holds an NFT of the verified `collection`, as set in its token metadata.
+    Collection { collection: Pubkey },
+}
+
+impl BidGate {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            BidGate::Open => Ok(()),
+            BidGate::Token { mint, min_amount } => {
+                require!(*mint != Pubkey::default() && *min_amount > 0, AuctionError::InvalidBidGate);
+                Ok(())
+            }
+            BidGate::Collection { collection } => {
+                require!(*collection != Pubkey::default(), AuctionError::InvalidBidGate);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+    fn legacy_auction2() -> BoyncAuction2Legacy {
+        BoyncAuction2Legacy {
+            id: 1_700_000_000_000,
+            start_auction_at: 1_700_000_000_000,
+            end_auction_at: 1_700_001_800_000,
+            authority: Pubkey::new_unique(),
+            treasury_mint: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            bidders_chest: Pubkey::new_unique(),
+            starting_price: LAMPORTS_PER_SOL / 10,
+            next_bid: LAMPORTS_PER_SOL / 10,
+            claimed: 0,
+            state: AuctionState::Active,
+            last_bidder: Pubkey::default(),
+            bump: 255,
+        }
+    }
+
+    fn legacy_auction3() -> BoyncAuction3Legacy {
+        BoyncAuction3Legacy {
+            id: 1_700_000_000_000,
+            start_auction_at: 1_700_000_000_000,
+            end_auction_at: 1_700_001_800_000,
+            authority: Pubkey::new_unique(),
+            treasury_mint: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            chest: Pubkey::new_unique(),
+            current_bid: 0,
+            claimed: 0,
+            state: AuctionState::Active,
+            last_bidder: Pubkey::default(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn reserve_met_without_reserve_price() {
+        let auction = legacy_auction2().migrate().unwrap();
+        assert!(auction.reserve_met());
+
+        let auction = legacy_auction3().migrate();
+        assert!(auction.reserve_met());
+    }
+
+    #[test]
+    fn reserve_met_from_reserve_price() {
+        let mut auction = legacy_auction2().migrate().unwrap();
+        auction.reserve_price = Some(LAMPORTS_PER_SOL);
+
+        auction.highest_bid = LAMPORTS_PER_SOL - 1;
+        assert!(!auction.reserve_met());
+
+        auction.highest_bid = LAMPORTS_PER_SOL;
+        assert!(auction.reserve_met());
+
+        let mut auction = legacy_auction3().migrate();
+        auction.reserve_price = Some(LAMPORTS_PER_SOL);
+
+        auction.current_bid = LAMPORTS_PER_SOL - 1;
+        assert!(!auction.reserve_met());
+
+        auction.current_bid = LAMPORTS_PER_SOL + 1;
+        assert!(auction.reserve_met());
+    }
+
+    #[test]
+    fn settled_once_claimed_and_paid_out() {
+        let mut auction = legacy_auction2().migrate().unwrap();
+        auction.claimed = 1;
+        assert!(auction.settled(0));
+
+        auction.last_bidder = Pubkey::new_unique();
+        assert!(!auction.settled(0));
+
+        auction.proceeds = Some(ProceedsPayout {
+            seller: LAMPORTS_PER_SOL,
+            protocol_fee: 0,
+            royalties: 0,
+            crank_fee: 0,
+        });
+        assert!(auction.settled(0));
+
+        auction.claimed = 0;
+        assert!(!auction.settled(0));
+    }
+
+    #[test]
+    fn settled_once_claimed_and_refunded() {
+        let mut auction = legacy_auction2().migrate().unwrap();
+        auction.claimed = 1;
+        auction.last_bidder = Pubkey::new_unique();
+        auction.highest_bid = LAMPORTS_PER_SOL;
+        auction.reserve_price = Some(2 * LAMPORTS_PER_SOL);
+
+        // Bids are still waiting for `refund_bid`
+        assert!(!auction.settled(LAMPORTS_PER_SOL));
+        assert!(auction.settled(0));
+
+        // An empty chest doesn't settle a sold auction before the payout
+        auction.reserve_price = None;
+        assert!(!auction.settled(0));
+    }
+
+    #[test]
+    fn legacy_auction2_keeps_its_behaviour() {
+        let mut legacy = legacy_auction2();
+        legacy.last_bidder = Pubkey::new_unique();
+        legacy.next_bid = 105 * LAMPORTS_PER_SOL / 100;
+
+        let auction = legacy.migrate().unwrap();
+        assert_eq!(auction.authority, legacy.authority);
+        assert_eq!(auction.last_bidder, legacy.last_bidder);
+        assert_eq!(auction.next_bid, legacy.next_bid);
+        assert_eq!(auction.highest_bid, LAMPORTS_PER_SOL);
+        assert!(auction.state == AuctionState::Active);
+        assert!(auction.reserve_price.is_none());
+        assert!(auction.buy_now_price.is_none());
+        assert!(auction.time_extension == TimeExtensionPolicy::LEGACY);
+        assert!(auction.bid_increment == BidIncrement::LEGACY_V2);
+        assert_eq!(auction.crank_fee_bps, 0);
+        assert!(!auction.seller_splits.is_set());
+        assert!(!auction.proceeds_paid());
+        assert!(auction.bid_gate == BidGate::Open);
+
+        /* No bids yet, nothing was paid into the bidders_chest */
+        assert_eq!(legacy_auction2().migrate().unwrap().highest_bid, 0);
+    }
+
+    #[test]
+    fn legacy_auction3_keeps_its_behaviour() {
+        let mut legacy = legacy_auction3();
+        legacy.last_bidder = Pubkey::new_unique();
+        legacy.current_bid = 3 * V3_BID_INCREMENT;
+
+        let auction = legacy.migrate();
+        assert_eq!(auction.chest, legacy.chest);
+        assert_eq!(auction.current_bid, legacy.current_bid);
+        assert_eq!(auction.last_bidder, legacy.last_bidder);
+        assert!(auction.time_extension == TimeExtensionPolicy::LEGACY);
+        assert!(auction.bid_increment == BidIncrement::LEGACY_V3);
+        assert_eq!(auction.next_bid_token_cost().unwrap(), V3_BID_TOKEN_COST);
+        assert_eq!(auction.payment_deadline().unwrap(), legacy.end_auction_at + V3_PAYMENT_WINDOW);
+        assert!(!auction.has_runner_up());
+        assert_eq!(auction.bid_deposit, 0);
+        assert!(auction.bid_gate == BidGate::Open);
+    }
+
+    #[test]
+    fn time_extension_validate() {
+        assert!(TimeExtensionPolicy::LEGACY.validate().is_ok());
+        assert!(TimeExtensionPolicy::default() == TimeExtensionPolicy::LEGACY);
+
+        let policy = TimeExtensionPolicy { window: 0, amount: 0, max_extension: 0 };
+        assert!(policy.validate().is_ok());
+
+        for policy in [
+            TimeExtensionPolicy { window: -1, amount: 0, max_extension: 0 },
+            TimeExtensionPolicy { window: 0, amount: -1, max_extension: 0 },
+            TimeExtensionPolicy { window: 0, amount: 0, max_extension: -1 },
+            TimeExtensionPolicy { window: 0, amount: MAX_AUCTION_DURATION + 1, max_extension: 0 },
+            TimeExtensionPolicy { window: 0, amount: 0, max_extension: MAX_AUCTION_DURATION + 1 },
+            TimeExtensionPolicy { window: 0, amount: i64::MAX, max_extension: i64::MAX },
+        ] {
+            assert!(policy.validate().is_err());
+        }
+
+        let policy = TimeExtensionPolicy { window: i64::MAX, amount: MAX_AUCTION_DURATION, max_extension: MAX_AUCTION_DURATION };
+        assert!(policy.validate().is_ok());
+    }
+
+    #[test]
+    fn time_extension_only_within_window() {
+        let policy = TimeExtensionPolicy {
+            window: 5 * 60 * MS_IN_SEC,
+            amount: 2 * 60 * MS_IN_SEC,
+            max_extension: 10 * 60 * MS_IN_SEC,
+        };
+        let end_auction_at = 1_700_001_800_000;
+
+        /* Too early, outside the window */
+        assert_eq!(policy.extension(end_auction_at - 5 * 60 * MS_IN_SEC - 1, end_auction_at, 0), 0);
+
+        /* Window edges */
+        assert_eq!(policy.extension(end_auction_at - 5 * 60 * MS_IN_SEC, end_auction_at, 0), policy.amount);
+        assert_eq!(policy.extension(end_auction_at, end_auction_at, 0), policy.amount);
+
+        /* Ended auctions aren't extended */
+        assert_eq!(policy.extension(end_auction_at + 1, end_auction_at, 0), 0);
+    }
+
+    #[test]
+    fn time_extension_capped_by_max_extension() {
+        let policy = TimeExtensionPolicy {
+            window: 5 * 60 * MS_IN_SEC,
+            amount: 2 * 60 * MS_IN_SEC,
+            max_extension: 3 * 60 * MS_IN_SEC,
+        };
+        let end_auction_at = 1_700_001_800_000;
+        let current_timestamp = end_auction_at - MS_IN_SEC;
+
+        assert_eq!(policy.extension(current_timestamp, end_auction_at, 0), 2 * 60 * MS_IN_SEC);
+        assert_eq!(policy.extension(current_timestamp, end_auction_at, 2 * 60 * MS_IN_SEC), 60 * MS_IN_SEC);
+        assert_eq!(policy.extension(current_timestamp, end_auction_at, 3 * 60 * MS_IN_SEC), 0);
+        assert_eq!(policy.extension(current_timestamp, end_auction_at, 4 * 60 * MS_IN_SEC), 0);
+    }
+
+    #[test]
+    fn time_extension_legacy_extends_every_bid() {
+        let end_auction_at = 1_700_001_800_000;
+
+        /* A bid right after start and after hours of extensions both add 60 seconds */
+        assert_eq!(TimeExtensionPolicy::LEGACY.extension(0, end_auction_at, 0), 60 * MS_IN_SEC);
+        assert_eq!(
+            TimeExtensionPolicy::LEGACY.extension(end_auction_at, end_auction_at, 24 * 60 * 60 * MS_IN_SEC),
+            60 * MS_IN_SEC
+        );
+    }
+
+    #[test]
+    fn legacy_layouts_fit_their_accounts() {
+        assert!(legacy_auction2().try_to_vec().unwrap().len() <= BoyncAuction2Legacy::AUCTION_SIZE);
+        assert!(legacy_auction3().try_to_vec().unwrap().len() <= BoyncAuction3Legacy::AUCTION_SIZE);
+
+        let auction = legacy_auction2().migrate().unwrap();
+        assert!(auction.try_to_vec().unwrap().len() <= BoyncAuction2::AUCTION_SIZE);
+
+        let auction = legacy_auction3().migrate();
+        assert!(auction.try_to_vec().unwrap().len() <= BoyncAuction3::AUCTION_SIZE);
+    }
+
+    fn tiers(used: &[(u64, u64)]) -> [IncrementTier; MAX_INCREMENT_TIERS] {
+        let mut tiers = [IncrementTier::default(); MAX_INCREMENT_TIERS];
+        for (tier, (from, increment)) in tiers.iter_mut().zip(used) {
+            tier.from = *from;
+            tier.increment = *increment;
+        }
+        tiers
+    }
+
+    #[test]
+    fn legacy_schedules_are_preserved() {
+        assert_eq!(BidIncrement::LEGACY_V2.next_bid(150_000_000).unwrap(), 157_500_000);
+        assert_eq!(BidIncrement::LEGACY_V3.next_bid(0).unwrap(), LAMPORTS_PER_SOL / 100);
+    }
+
+    #[test]
+    fn tiered_schedule_uses_highest_reached_tier() {
+        let schedule = BidIncrement::Tiered {
+            tiers: tiers(&[(0, LAMPORTS_PER_SOL / 100), (LAMPORTS_PER_SOL, LAMPORTS_PER_SOL / 10)]),
+        };
+        schedule.validate().unwrap();
+
+        assert_eq!(schedule.next_bid(0).unwrap(), LAMPORTS_PER_SOL / 100);
+        assert_eq!(schedule.next_bid(LAMPORTS_PER_SOL - 1).unwrap(), LAMPORTS_PER_SOL - 1 + LAMPORTS_PER_SOL / 100);
+        assert_eq!(schedule.next_bid(LAMPORTS_PER_SOL).unwrap(), LAMPORTS_PER_SOL + LAMPORTS_PER_SOL / 10);
+    }
+
+    #[test]
+    fn invalid_schedules_are_rejected() {
+        assert!(BidIncrement::Fixed { amount: 0 }.validate().is_err());
+        assert!(BidIncrement::Percentage { bps: 0 }.validate().is_err());
+        assert!(BidIncrement::Percentage { bps: MAX_BASIS_POINTS + 1 }.validate().is_err());
+
+        /* First tier must start at 0 */
+        assert!(BidIncrement::Tiered { tiers: tiers(&[(1, 10)]) }.validate().is_err());
+        /* Tiers must be ascending */
+        assert!(BidIncrement::Tiered { tiers: tiers(&[(0, 10), (50, 20), (50, 30)]) }.validate().is_err());
+        /* No used tier after an unused one */
+        assert!(BidIncrement::Tiered { tiers: tiers(&[(0, 10), (0, 0), (50, 30)]) }.validate().is_err());
+    }
+
+    #[test]
+    fn bids_always_increase() {
+        let percentage = BidIncrement::Percentage { bps: 1 };
+
+        assert_eq!(percentage.next_bid(0).unwrap(), 1);
+        assert!(percentage.next_bid(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn bid_token_cost_rises_every_n_bids() {
+        let rise = BidCostRise { every_bids: 10, amount: 2 };
+        rise.validate().unwrap();
+
+        assert_eq!(rise.cost(1, 0).unwrap(), 1);
+        assert_eq!(rise.cost(1, 9).unwrap(), 1);
+        assert_eq!(rise.cost(1, 10).unwrap(), 3);
+        assert_eq!(rise.cost(1, 25).unwrap(), 5);
+    }
+
+    #[test]
+    fn invalid_rise_is_rejected() {
+        assert!(BidCostRise { every_bids: 0, amount: 1 }.validate().is_err());
+        assert!(BidCostRise { every_bids: 1, amount: 0 }.validate().is_err());
+        assert!(BidCostRise { every_bids: 1, amount: u64::MAX }.cost(1, 1).is_err());
+    }
+
+    fn splits(used: &[(Pubkey, u16)]) -> SellerSplits {
+        let mut seller_splits = SellerSplits::default();
+        for (split, (recipient, bps)) in seller_splits.splits.iter_mut().zip(used) {
+            split.recipient = *recipient;
+            split.bps = *bps;
+        }
+        seller_splits
+    }
+
+    #[test]
+    fn no_splits_is_valid() {
+        let seller_splits = SellerSplits::default();
+
+        seller_splits.validate().unwrap();
+        assert!(!seller_splits.is_set());
+    }
+
+    #[test]
+    fn splits_must_add_up_to_100_percent() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        splits(&[(a, 7_000), (b, 3_000)]).validate().unwrap();
+        assert!(splits(&[(a, 7_000), (b, 2_000)]).validate().is_err());
+        assert!(splits(&[(a, 7_000), (b, 4_000)]).validate().is_err());
+        assert!(splits(&[(a, u16::MAX), (b, 2)]).validate().is_err());
+    }
+
+    #[test]
+    fn invalid_splits_are_rejected() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        /* Used split needs a recipient */
+        assert!(splits(&[(a, 5_000), (Pubkey::default(), 5_000)]).validate().is_err());
+        /* Unused split can't have a recipient */
+        assert!(splits(&[(a, MAX_BASIS_POINTS), (b, 0)]).validate().is_err());
+        /* No used split after an unused one */
+        let mut gap = splits(&[(a, 5_000)]);
+        gap.splits[2] = SellerSplit { recipient: b, bps: 5_000 };
+        assert!(gap.validate().is_err());
+    }
+}
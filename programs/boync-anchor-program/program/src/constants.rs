@@ -3,11 +3,37 @@ pub const TREASURY_PREFIX: &str = "treasury";
 pub const CHEST_PREFIX: &str = "chest";
 pub const AUCTION_PREFIX: &str = "auction";
 pub const BIDDER_PREFIX: &str = "bidder";
+pub const BID_COUNTER_PREFIX: &str = "bid_counter";
+pub const CONFIG_PREFIX: &str = "config";
+pub const DEFAULTER_PREFIX: &str = "defaulter";
+pub const DEPOSIT_PREFIX: &str = "deposit";
+pub const PROXY_PREFIX: &str = "proxy";
 
 pub const TREASURY_SEED: &[u8] = b"treasury";
 // pub const WALLET_SEED: &[u8] = b"wallet";
 pub const CHEST_SEED: &[u8] = b"chest";
 pub const AUCTION_SEED: &[u8] = b"auction";
 pub const BIDDER_SEED: &[u8] = b"bidder";
+pub const BID_COUNTER_SEED: &[u8] = b"bid_counter";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const DEFAULTER_SEED: &[u8] = b"defaulter";
+pub const DEPOSIT_SEED: &[u8] = b"deposit";
+pub const PROXY_SEED: &[u8] = b"proxy";
 
-pub const MS_IN_SEC: i64 = 1000;
\ No newline at end of file
+pub const MS_IN_SEC: i64 = 1000;
+
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+pub const STARTING_PRICE_BPS: u16 = 500; // 5% of the floor price
+pub const BID_INCREMENT_BPS: u16 = 500; // next_bid grows 5% per bid
+pub const V3_BID_INCREMENT: u64 = 10_000_000; // 0.01 SOL per bid
+pub const V3_BID_TOKEN_COST: u64 = 1; // chest tokens per bid
+pub const MAX_INCREMENT_TIERS: usize = 4;
+pub const MAX_CRANK_FEE_BPS: u16 = 100; // keepers get at most 1% of the proceeds for settling
+pub const MAX_SELLER_SPLITS: usize = 5;
+
+pub const MIN_AUCTION_DURATION: i64 = 60 * MS_IN_SEC; // 1 minute
+pub const MAX_AUCTION_DURATION: i64 = 30 * 24 * 60 * 60 * MS_IN_SEC; // 30 days
+pub const MAX_START_IN_PAST: i64 = 5 * 60 * MS_IN_SEC; // 5 minutes
+pub const MIN_TIMESTAMP_MS: i64 = 1_000_000_000_000; // Sep 2001, second based timestamps fall way below
+pub const V3_PAYMENT_WINDOW: i64 = 3 * 24 * 60 * 60 * MS_IN_SEC; // 3 days for the v3 winner to pay
+pub const MAX_TIMESTAMP_MS: i64 = 100_000_000_000_000; // Year 5138
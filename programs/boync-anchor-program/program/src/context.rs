@@ -9,9 +9,60 @@ use anchor_spl::{
 };
 
 use crate::constants::*;
+use crate::program::BoyncAnchorProgram;
 use crate::utils::TokenMetadataProgram;
 use crate::errors::AuctionError;
-use crate::account::{BoyncAuction2, BoyncAuction, BoyncAuction3, BoyncUserBid};
+use crate::account::{
+    BoyncAuction2, BoyncAuction, BoyncAuction3, BoyncEnglishAuction, BoyncDutchAuction,
+    BoyncSealedAuction, BoyncSealedBid, BoyncUserBid, BoyncBidderCounter, BoyncConfig,
+    BoyncDefaulter, BoyncBidDeposit, BoyncProxyBid,
+};
+
+/**
+ * Protocol
+ * Program upgrade authority initializes the config and becomes its admin, done once right after deployment.
+ */
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + BoyncConfig::ACCOUNT_SIZE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, BoyncConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ AuctionError::InvalidProgramData)]
+    pub program: Program<'info, BoyncAnchorProgram>,
+
+    /// Holds the upgrade authority, which has to be the admin.
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ AuctionError::NotUpgradeAuthority)]
+    pub program_data: Account<'info, ProgramData>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Protocol
+ * Admin updates the protocol fee and its recipient, or hands the config over to a new admin.
+ */
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        has_one = admin @ AuctionError::InvalidAuthority,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, BoyncConfig>,
+
+    pub admin: Signer<'info>,
+}
 
 #[derive(Accounts)]
 #[instruction(app_idx: i64, state_bump: u8)]
@@ -93,7 +144,7 @@ pub struct InitializeAuction2<'info> {
     #[account(
         init,
         payer = signer,
-        space = 8 + BoyncAuction::AUCTION_SIZE,
+        space = 8 + BoyncAuction2::AUCTION_SIZE,
         seeds = [
             AUCTION_SEED,
             signer.key().as_ref(),
@@ -292,66 +343,167 @@ pub struct InitializeAuction3<'info> {
 }
 
 #[derive(Accounts)]
-pub struct ClaimRewards<'info> {
+#[instruction(app_idx: i64, state_bump: u8, starting_price: u64, min_bid_increment: u64, start_at: i64, end_at: i64)]
+pub struct InitializeEnglishAuction<'info> {
+    /// State of our auction program, also escrows the highest bid
     #[account(
-        mut,
-        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        init,
+        payer = signer,
+        space = 8 + BoyncEnglishAuction::AUCTION_SIZE,
+        seeds = [
+            AUCTION_SEED,
+            signer.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            app_idx.to_le_bytes().as_ref(),
+        ],
         bump
     )]
-    pub state: Box<Account<'info, BoyncAuction2>>,
+    pub state: Box<Account<'info, BoyncEnglishAuction>>,
 
-    /// Token Account holding token being auctioned.
     #[account(
-        mut,
-        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        init,
+        payer = signer,
+        seeds = [
+            TREASURY_SEED,
+            signer.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            app_idx.to_le_bytes().as_ref(),
+        ],
         bump,
-        token::mint=treasury_mint,
-        token::authority=state
+        token::mint = treasury_mint,
+        token::authority = state
     )]
+    /// Token Account holding token being auctioned.
     pub treasury: Box<Account<'info, TokenAccount>>,
 
+    // Users and accounts in the system
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
     /// Mint for SPL Token stored in treasury.
-    pub treasury_mint: Account<'info, Mint>,
+    pub treasury_mint: Box<Account<'info, Mint>>,
 
-    // Users and accounts in the system
+    /// CHECK: Metadata Account
+    /// verified in `initialize_english_auction`
     #[account(mut)]
-    pub winner: Signer<'info>,
+    pub metadata: UncheckedAccount<'info>,
 
-    /// Winner's SPL Token account wallet
-    /// (The wallet who will receive the auctioned token(s))
+    /// CHECK: Edition Account
+    /// verified in `initialize_english_auction`
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified in `initialize_english_auction`
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified in `initialize_english_auction`
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: PDA checked by anchor
+    pub auth_rules: UncheckedAccount<'info>,
+
+    /// SPL Token account for Signer wallet
+    /// (The wallet who will send the Token being auctioned)
     #[account(
         init_if_needed,
-        payer = winner,
         associated_token::mint = treasury_mint,
-        associated_token::authority = winner,
-        constraint = winner_token_account.owner == winner.key(),
-        constraint = winner_token_account.mint == treasury_mint.key()
+        associated_token::authority = signer,
+        payer = signer
     )]
-    pub winner_token_account: Account<'info, TokenAccount>,
+    pub signer_token_account: Box<Account<'info, TokenAccount>>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    rent: Sysvar<'info, Rent>,
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(app_idx: i64, state_bump: u8, start_price: u64, floor_price: u64, start_at: i64, end_at: i64)]
+pub struct InitializeDutchAuction<'info> {
+    /// State of our auction program (up to you)
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + BoyncDutchAuction::AUCTION_SIZE,
+        seeds = [
+            AUCTION_SEED,
+            signer.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            app_idx.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncDutchAuction>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            TREASURY_SEED,
+            signer.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            app_idx.to_le_bytes().as_ref(),
+        ],
+        bump,
+        token::mint = treasury_mint,
+        token::authority = state
+    )]
+    /// Token Account holding token being auctioned.
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Box<Account<'info, Mint>>,
 
     /// CHECK: Metadata Account
-    /// verified part of the mpl_metadata_token::transfer
+    /// verified in `initialize_dutch_auction`
     #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
 
     /// CHECK: Edition Account
-    /// verified part of the mpl_metadata_token::transfer
+    /// verified in `initialize_dutch_auction`
     pub edition: UncheckedAccount<'info>,
 
     /// CHECK: Owner Token Record Account
-    /// verified part of the mpl_metadata_token::transfer
+    /// verified in `initialize_dutch_auction`
     #[account(mut)]
     pub owner_token_record: UncheckedAccount<'info>,
 
     /// CHECK: Owner Token Record Account
-    /// verified part of the mpl_metadata_token::transfer
+    /// verified in `initialize_dutch_auction`
     #[account(mut)]
     pub destination_token_record: UncheckedAccount<'info>,
 
-    /// CHECK: Authorization Rules account
-    /// verified part of the mpl_metadata_token::transfer
+    /// CHECK: PDA checked by anchor
     pub auth_rules: UncheckedAccount<'info>,
 
+    /// SPL Token account for Signer wallet
+    /// (The wallet who will send the Token being auctioned)
+    #[account(
+        init_if_needed,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = signer,
+        payer = signer
+    )]
+    pub signer_token_account: Box<Account<'info, TokenAccount>>,
+
     // Application level accounts
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -362,21 +514,109 @@ pub struct ClaimRewards<'info> {
     pub auth_rules_token_program: UncheckedAccount<'info>,
     #[account(address = mpl_token_metadata::id())]
     pub token_metadata_program: Program<'info, TokenMetadataProgram>,
-    pub rent: Sysvar<'info, Rent>,
+    rent: Sysvar<'info, Rent>,
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(app_idx: i64, state_bump: u8, starting_price: u64, min_deposit: u64, forfeit_bps: u16, start_at: i64, end_at: i64, reveal_end_at: i64)]
+pub struct InitializeSealedAuction<'info> {
+    /// State of our auction program (up to you)
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + BoyncSealedAuction::AUCTION_SIZE,
+        seeds = [
+            AUCTION_SEED,
+            signer.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            app_idx.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncSealedAuction>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            TREASURY_SEED,
+            signer.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            app_idx.to_le_bytes().as_ref(),
+        ],
+        bump,
+        token::mint = treasury_mint,
+        token::authority = state
+    )]
+    /// Token Account holding token being auctioned.
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Metadata Account
+    /// verified in `initialize_sealed_auction`
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified in `initialize_sealed_auction`
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified in `initialize_sealed_auction`
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified in `initialize_sealed_auction`
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: PDA checked by anchor
+    pub auth_rules: UncheckedAccount<'info>,
+
+    /// SPL Token account for Signer wallet
+    /// (The wallet who will send the Token being auctioned)
+    #[account(
+        init_if_needed,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = signer,
+        payer = signer
+    )]
+    pub signer_token_account: Box<Account<'info, TokenAccount>>,
 
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    rent: Sysvar<'info, Rent>,
     #[account(address = sysvar::instructions::id())]
     /// CHECK: Sysvar Instructions
     pub sysvar_instructions: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimRewards3<'info> {
+pub struct ClaimRewards<'info> {
     #[account(
         mut,
         seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
         bump
     )]
-    pub state: Box<Account<'info, BoyncAuction3>>,
+    pub state: Box<Account<'info, BoyncAuction2>>,
 
     /// Token Account holding token being auctioned.
     #[account(
@@ -391,6 +631,17 @@ pub struct ClaimRewards3<'info> {
     /// Mint for SPL Token stored in treasury.
     pub treasury_mint: Account<'info, Mint>,
 
+    /// CHECK: only read, the state is kept while bids are left to refund
+    #[account(
+        seeds = [CHEST_SEED, state.authority.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bidders_chest: AccountInfo<'info>,
+
+    /// CHECK: Auction authority, receives the treasury and state rent.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
     // Users and accounts in the system
     #[account(mut)]
     pub winner: Signer<'info>,
@@ -447,8 +698,1356 @@ pub struct ClaimRewards3<'info> {
     pub sysvar_instructions: UncheckedAccount<'info>,
 }
 
-/*
- * [DEPRECATED]
+#[derive(Accounts)]
+pub struct ClaimRewards3<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the treasury and state rent.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// Winner's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = winner,
+        constraint = winner_token_account.owner == winner.key(),
+        constraint = winner_token_account.mint == treasury_mint.key()
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/**
+ * V2
+ * Permissionless settlement, any keeper can deliver the NFT and route proceeds once the auction ended.
+ */
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction2>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: only used as a signing PDA
+    #[account(
+        mut,
+        seeds = [CHEST_SEED, state.authority.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bidders_chest: AccountInfo<'info>,
+
+    /// CHECK: Auction authority, receives the proceeds.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Winner, or the authority when the auction wasn't sold.
+    /// verified in `settle`
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Recipient's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Anyone can settle, pays for the recipient's token account.
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/**
+ * V3
+ * Permissionless settlement of an auction that wasn't sold, the NFT goes back to the authority.
+ * Winners pay at claim time so a sold auction is settled through `claim_rewards3`.
+ */
+#[derive(Accounts)]
+pub struct Settle3<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the token back.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Authority's SPL Token account wallet
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = authority,
+    )]
+    pub authority_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Anyone can settle, pays for the authority's token account.
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/**
+ * V3
+ * Winner didn't pay within the payment window, the authority takes the token back.
+ */
+#[derive(Accounts)]
+pub struct ReclaimDefaulted3<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Authority's SPL Token account wallet
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = authority,
+    )]
+    pub authority_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Flags the winner that didn't pay.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + BoyncDefaulter::ACCOUNT_SIZE,
+        seeds = [DEFAULTER_SEED, state.last_bidder.as_ref()],
+        bump
+    )]
+    pub defaulter: Box<Account<'info, BoyncDefaulter>>,
+
+    /// CHECK: Defaulter's deposit, slashed to the authority.
+    /// Always required, left empty only for bidders from before deposits were locked.
+    /// verified in `slash_defaulter_deposit`
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, DEPOSIT_SEED, state.key().as_ref(), state.last_bidder.as_ref()],
+        bump
+    )]
+    pub defaulter_deposit: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/**
+ * V3
+ * Winner didn't pay within the payment window, the runner-up gets to buy at their last price.
+ */
+#[derive(Accounts)]
+pub struct OfferRunnerUp3<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Flags the winner that didn't pay.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + BoyncDefaulter::ACCOUNT_SIZE,
+        seeds = [DEFAULTER_SEED, state.last_bidder.as_ref()],
+        bump
+    )]
+    pub defaulter: Box<Account<'info, BoyncDefaulter>>,
+
+    /// CHECK: Defaulter's deposit, slashed to the authority.
+    /// Always required, left empty only for bidders from before deposits were locked.
+    /// verified in `slash_defaulter_deposit`
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, DEPOSIT_SEED, state.key().as_ref(), state.last_bidder.as_ref()],
+        bump
+    )]
+    pub defaulter_deposit: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * V3
+ * Bidder's deposit is returned once the auction is settled, anyone can crank it.
+ */
+#[derive(Accounts)]
+pub struct RefundDeposit3<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, DEPOSIT_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        has_one = bidder @ AuctionError::PublicKeyMismatch,
+        close = bidder
+    )]
+    pub deposit: Box<Account<'info, BoyncBidDeposit>>,
+
+    /// CHECK: Bidder, gets the deposit and the rent back.
+    #[account(mut)]
+    pub bidder: UncheckedAccount<'info>,
+}
+
+/**
+ * V3
+ * Authority withdraws the winning payment held by the auction state.
+ */
+#[derive(Accounts)]
+pub struct WithdrawProceeds3<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    /// CHECK: Chest of the auction, the state is closed once the chest is.
+    #[account(address = state.chest @ AuctionError::PublicKeyMismatch)]
+    pub chest: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/**
+ * V3
+ * Authority withdraws the tokens spent on bids, the chest is closed afterwards.
+ */
+#[derive(Accounts)]
+pub struct WithdrawChest3<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    /// Account which holds tokens bidded by biders
+    #[account(
+        mut,
+        address = state.chest,
+        token::authority=state
+    )]
+    pub chest: Box<Account<'info, TokenAccount>>,
+
+    /// Authority's SPL Token account for the chest mint
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key(),
+        constraint = authority_token_account.mint == chest.mint,
+    )]
+    pub authority_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Application level accounts
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewardsEnglish<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncEnglishAuction>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the highest bid.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// Winner's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = winner,
+        constraint = winner_token_account.owner == winner.key(),
+        constraint = winner_token_account.mint == treasury_mint.key()
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyDutch<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncDutchAuction>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the price paid and the state rent.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Buyer's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = buyer,
+        constraint = buyer_token_account.owner == buyer.key(),
+        constraint = buyer_token_account.mint == treasury_mint.key()
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNow<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction2>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the price paid.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Buyer's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = buyer,
+        constraint = buyer_token_account.owner == buyer.key(),
+        constraint = buyer_token_account.mint == treasury_mint.key()
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNow3<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the price paid.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Buyer's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = buyer,
+        constraint = buyer_token_account.owner == buyer.key(),
+        constraint = buyer_token_account.mint == treasury_mint.key()
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyNowEnglish<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncEnglishAuction>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the price paid.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Outbid user, refunded the highest bid.
+    /// Not needed while no bids have been placed.
+    #[account(mut, address = state.last_bidder @ AuctionError::PublicKeyMismatch)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Leader's proxy escrow, only used when the leader bid by proxy.
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, PROXY_SEED, state.key().as_ref(), state.last_bidder.as_ref()],
+        bump
+    )]
+    pub leader_proxy: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Buyer's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = buyer,
+        constraint = buyer_token_account.owner == buyer.key(),
+        constraint = buyer_token_account.mint == treasury_mint.key()
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewardsDutch<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncDutchAuction>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the treasury and state rent.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// Winner's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = winner,
+        constraint = winner_token_account.owner == winner.key(),
+        constraint = winner_token_account.mint == treasury_mint.key()
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewardsSealed<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Box<Account<'info, BoyncSealedAuction>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: Auction authority, receives the clearing price.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// Winner's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token(s))
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = winner,
+        constraint = winner_token_account.owner == winner.key(),
+        constraint = winner_token_account.mint == treasury_mint.key()
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+
+    /// Winner's sealed bid, pays the clearing price and is closed back to the winner.
+    /// Not needed when no bid has been revealed.
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, state.key().as_ref(), winner.key().as_ref()],
+        bump,
+        close = winner
+    )]
+    pub winner_bid: Option<Account<'info, BoyncSealedBid>>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAuctionConfig<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncAuction2>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAuctionConfig3<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncAuction3>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        close = authority
+    )]
+    pub state: Box<Account<'info, BoyncAuction2>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Authority's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token back)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = authority,
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuction3<'info> {
+    #[account(
+        mut,
+        has_one = authority @ AuctionError::InvalidAuthority,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        close = authority
+    )]
+    pub state: Box<Account<'info, BoyncAuction3>>,
+
+    /// Token Account holding token being auctioned.
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump,
+        token::mint=treasury_mint,
+        token::authority=state
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Account which holds tokens bidded by biders, empty until the first bid.
+    #[account(
+        mut,
+        address = state.chest,
+        token::authority=state
+    )]
+    pub chest: Box<Account<'info, TokenAccount>>,
+
+    /// Mint for SPL Token stored in treasury.
+    pub treasury_mint: Account<'info, Mint>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Authority's SPL Token account wallet
+    /// (The wallet who will receive the auctioned token back)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = treasury_mint,
+        associated_token::authority = authority,
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    /// Authority's SPL Token account for the chest mint, receives bid tokens sent straight to the chest.
+    /// Not needed while the chest is empty.
+    #[account(
+        mut,
+        constraint = authority_chest_token_account.owner == authority.key(),
+        constraint = authority_chest_token_account.mint == chest.mint,
+    )]
+    pub authority_chest_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Metadata Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition Account
+    /// verified part of the mpl_metadata_token::transfer
+    pub edition: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Owner Token Record Account
+    /// verified part of the mpl_metadata_token::transfer
+    #[account(mut)]
+    pub destination_token_record: UncheckedAccount<'info>,
+
+    /// CHECK: Authorization Rules account
+    /// verified part of the mpl_metadata_token::transfer
+    pub auth_rules: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = mpl_token_auth_rules::id())]
+    /// CHECK: Authorization Rules account
+    /// verified in `mpl_token_transfer`
+    pub auth_rules_token_program: UncheckedAccount<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: Program<'info, TokenMetadataProgram>,
+    pub rent: Sysvar<'info, Rent>,
+
+    #[account(address = sysvar::instructions::id())]
+    /// CHECK: Sysvar Instructions
+    pub sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/*
+ * [DEPRECATED]
  *
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
@@ -487,11 +2086,189 @@ pub struct ClaimRewards<'info> {
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
 }
-*/
+*/
+
+#[derive(Accounts)]
+pub struct EndAuction<'info> {
+    #[account(mut, has_one = authority @ AuctionError::InvalidAuthority)]
+    pub state: Account<'info, BoyncAuction2>,
+
+    /// CHECK: only used as a signing PDA
+    #[account(
+        mut,
+        seeds = [CHEST_SEED, state.authority.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bidders_chest: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Protocol fee settings
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, BoyncConfig>>,
+
+    /// CHECK: Receives the protocol fee, checked against the config.
+    #[account(mut, address = config.fee_recipient @ AuctionError::PublicKeyMismatch)]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata Account
+    /// verified against the auctioned mint when paying creator royalties
+    pub metadata: UncheckedAccount<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/**
+ * V2
+ * Users use SOL to bid.
+ * [BA-Program-5uJBi4jN][MVP] Remove BOYNC token GATE
+ */
+#[derive(Accounts)]
+#[instruction(ts: i64)]
+pub struct UpdateAuction2<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncAuction2>,
+
+    /// CHECK: only used as a signing PDA
+    #[account(
+        mut,
+        seeds = [CHEST_SEED, state.authority.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bidders_chest: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BoyncUserBid::ACCOUNT_SIZE,
+        seeds = [
+            BIDDER_SEED,
+            state.key().as_ref(),
+            bidder.key().as_ref(),
+            ts.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub bidder_state: Account<'info, BoyncUserBid>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/**
+ * V2
+ * Users use SOL to bid, bid records are keyed by the bidder's sequence number.
+ */
+#[derive(Accounts)]
+pub struct PlaceBid2<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncAuction2>,
+
+    /// CHECK: only used as a signing PDA
+    #[account(
+        mut,
+        seeds = [CHEST_SEED, state.authority.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bidders_chest: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BoyncBidderCounter::ACCOUNT_SIZE,
+        seeds = [BID_COUNTER_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bidder_counter: Account<'info, BoyncBidderCounter>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + BoyncUserBid::ACCOUNT_SIZE,
+        seeds = [
+            BIDDER_SEED,
+            state.key().as_ref(),
+            bidder.key().as_ref(),
+            bidder_counter.next_seq().to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub bidder_state: Account<'info, BoyncUserBid>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/**
+ * V2
+ * Grows an auction created before reserve prices into the current layout.
+ */
+#[derive(Accounts)]
+pub struct MigrateAuction2<'info> {
+    /// CHECK: Legacy layout doesn't deserialize as `BoyncAuction2`,
+    /// verified in `migrate_auction2`
+    #[account(mut, owner = crate::ID)]
+    pub state: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * V3
+ * Grows an auction created before reserve prices into the current layout.
+ */
+#[derive(Accounts)]
+pub struct MigrateAuction3<'info> {
+    /// CHECK: Legacy layout doesn't deserialize as `BoyncAuction3`,
+    /// verified in `migrate_auction3`
+    #[account(mut, owner = crate::ID)]
+    pub state: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+}
 
+/**
+ * V2
+ * Bidders get their SOL back when the reserve price was not met.
+ */
 #[derive(Accounts)]
-pub struct EndAuction<'info> {
-    #[account(mut, has_one = authority @ AuctionError::InvalidAuthority)]
+pub struct RefundBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
     pub state: Account<'info, BoyncAuction2>,
 
     /// CHECK: only used as a signing PDA
@@ -502,40 +2279,82 @@ pub struct EndAuction<'info> {
     )]
     pub bidders_chest: AccountInfo<'info>,
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = bidder @ AuctionError::PublicKeyMismatch,
+        constraint = bidder_state.auction == state.key() @ AuctionError::PublicKeyMismatch,
+        close = bidder
+    )]
+    pub bidder_state: Account<'info, BoyncUserBid>,
+
+    /// CHECK: Auction authority, receives the state rent after the last refund.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
 
+    // Users and accounts in the system
     #[account(mut)]
-    /// CHECK: Treasury account will be one random account that only receives SOL.
-    pub treasury: AccountInfo<'info>,
+    pub bidder: Signer<'info>,
 
     // Application level accounts
     pub system_program: Program<'info, System>,
-    rent: Sysvar<'info, Rent>,
 }
 
 /**
- * V2
- * Users use SOL to bid.
+ * V2 / V3 / English
+ * Bidders get the rent of their bid records back once the auction is settled.
+ */
+#[derive(Accounts)]
+pub struct CloseBidRecord<'info> {
+    /// CHECK: Auction the bid was placed on, closed at settlement or settled.
+    /// verified in `assert_bid_record_closable`
+    #[account(address = bidder_state.auction @ AuctionError::PublicKeyMismatch)]
+    pub auction: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bidder @ AuctionError::PublicKeyMismatch,
+        close = bidder
+    )]
+    pub bidder_state: Account<'info, BoyncUserBid>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+}
+
+/**
+ * V3
+ * Users use Tokens to bid.
  * [BA-Program-5uJBi4jN][MVP] Remove BOYNC token GATE
  */
 #[derive(Accounts)]
 #[instruction(ts: i64)]
-pub struct UpdateAuction2<'info> {
+pub struct UpdateAuction3<'info> {
     #[account(
         mut,
         seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
         bump
     )]
-    pub state: Account<'info, BoyncAuction2>,
+    pub state: Account<'info, BoyncAuction3>,
 
-    /// CHECK: only used as a signing PDA
     #[account(
-        mut,
-        seeds = [CHEST_SEED, state.authority.key().as_ref(), state.id.to_le_bytes().as_ref()],
-        bump
+        init_if_needed,
+        payer = bidder,
+        seeds = [
+            CHEST_SEED,
+            state.authority.key().as_ref(),
+            chest_mint.key().as_ref(),
+            state.id.to_le_bytes().as_ref(),
+        ],
+        bump,
+        token::mint = chest_mint,
+        token::authority = state
     )]
-    pub bidders_chest: AccountInfo<'info>,
+    /// Account which holds tokens bidded by biders
+    pub chest: Account<'info, TokenAccount>,
+
+    /// Mint for SPL Token stored in bidder's chest.
+    pub chest_mint: Account<'info, Mint>,
 
     #[account(
         init_if_needed,
@@ -551,23 +2370,39 @@ pub struct UpdateAuction2<'info> {
     )]
     pub bidder_state: Account<'info, BoyncUserBid>,
 
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BoyncBidDeposit::ACCOUNT_SIZE,
+        seeds = [BIDDER_SEED, DEPOSIT_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub deposit: Box<Account<'info, BoyncBidDeposit>>,
+
+    /// Payer's SPL Token account wallet
+    #[account(
+        mut,
+        constraint=bidder_token_account.owner == bidder.key(),
+        constraint=bidder_token_account.mint == chest_mint.key(),
+    )]
+    pub bidder_token_account: Account<'info, TokenAccount>,
+
     // Users and accounts in the system
     #[account(mut)]
     pub bidder: Signer<'info>,
 
     // Application level accounts
     pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
     rent: Sysvar<'info, Rent>,
 }
 
 /**
  * V3
- * Users use Tokens to bid.
- * [BA-Program-5uJBi4jN][MVP] Remove BOYNC token GATE
+ * Users use Tokens to bid, bid records are keyed by the bidder's sequence number.
  */
 #[derive(Accounts)]
-#[instruction(ts: i64)]
-pub struct UpdateAuction3<'info> {
+pub struct PlaceBid3<'info> {
     #[account(
         mut,
         seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
@@ -597,17 +2432,35 @@ pub struct UpdateAuction3<'info> {
     #[account(
         init_if_needed,
         payer = bidder,
+        space = 8 + BoyncBidderCounter::ACCOUNT_SIZE,
+        seeds = [BID_COUNTER_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bidder_counter: Account<'info, BoyncBidderCounter>,
+
+    #[account(
+        init,
+        payer = bidder,
         space = 8 + BoyncUserBid::ACCOUNT_SIZE,
         seeds = [
             BIDDER_SEED,
             state.key().as_ref(),
             bidder.key().as_ref(),
-            ts.to_le_bytes().as_ref(),
+            bidder_counter.next_seq().to_le_bytes().as_ref(),
         ],
         bump
     )]
     pub bidder_state: Account<'info, BoyncUserBid>,
 
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BoyncBidDeposit::ACCOUNT_SIZE,
+        seeds = [BIDDER_SEED, DEPOSIT_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub deposit: Box<Account<'info, BoyncBidDeposit>>,
+
     /// Payer's SPL Token account wallet
     #[account(
         mut,
@@ -626,6 +2479,224 @@ pub struct UpdateAuction3<'info> {
     rent: Sysvar<'info, Rent>,
 }
 
+/**
+ * English
+ * Users bid any amount of SOL above the minimum next bid, bid records are keyed by the bidder's sequence number.
+ */
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncEnglishAuction>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BoyncBidderCounter::ACCOUNT_SIZE,
+        seeds = [BID_COUNTER_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bidder_counter: Account<'info, BoyncBidderCounter>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + BoyncUserBid::ACCOUNT_SIZE,
+        seeds = [
+            BIDDER_SEED,
+            state.key().as_ref(),
+            bidder.key().as_ref(),
+            bidder_counter.next_seq().to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub bidder_state: Account<'info, BoyncUserBid>,
+
+    /// CHECK: Outbid user, refunded the previous highest bid.
+    /// Not needed while no bids have been placed.
+    #[account(mut, address = state.last_bidder @ AuctionError::PublicKeyMismatch)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Leader's proxy escrow, only used when the leader bid by proxy.
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, PROXY_SEED, state.key().as_ref(), state.last_bidder.as_ref()],
+        bump
+    )]
+    pub leader_proxy: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/**
+ * English
+ * Users escrow up to a maximum price, the program bids the minimum needed for them.
+ */
+#[derive(Accounts)]
+pub struct PlaceProxyBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncEnglishAuction>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BoyncProxyBid::ACCOUNT_SIZE,
+        seeds = [BIDDER_SEED, PROXY_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub proxy: Account<'info, BoyncProxyBid>,
+
+    /// CHECK: Leader's proxy escrow, only used when the leader bid by proxy.
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, PROXY_SEED, state.key().as_ref(), state.last_bidder.as_ref()],
+        bump
+    )]
+    pub leader_proxy: UncheckedAccount<'info>,
+
+    /// CHECK: Outbid user, refunded the previous highest bid.
+    /// Not needed while no bids have been placed.
+    #[account(mut, address = state.last_bidder @ AuctionError::PublicKeyMismatch)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * English
+ * Bidders take their proxy escrow back once it no longer backs the highest bid.
+ */
+#[derive(Accounts)]
+pub struct WithdrawProxyBid<'info> {
+    #[account(
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncEnglishAuction>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, PROXY_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        has_one = bidder @ AuctionError::PublicKeyMismatch,
+        close = bidder
+    )]
+    pub proxy: Account<'info, BoyncProxyBid>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+}
+
+/**
+ * Sealed
+ * Users commit hash(amount, salt) and lock a deposit.
+ */
+#[derive(Accounts)]
+pub struct CommitBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncSealedAuction>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + BoyncSealedBid::ACCOUNT_SIZE,
+        seeds = [BIDDER_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bidder_state: Account<'info, BoyncSealedBid>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/**
+ * Sealed
+ * Users reveal (amount, salt) once the commit window is over.
+ */
+#[derive(Accounts)]
+pub struct RevealBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncSealedAuction>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        has_one = bidder @ AuctionError::PublicKeyMismatch
+    )]
+    pub bidder_state: Account<'info, BoyncSealedBid>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    // Application level accounts
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Sealed
+ * Losing and unrevealed bidders get their deposit back, minus the forfeit for unrevealed bids.
+ */
+#[derive(Accounts)]
+pub struct WithdrawSealedBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, state.authority.key().as_ref(), state.treasury_mint.key().as_ref(), state.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub state: Account<'info, BoyncSealedAuction>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_SEED, state.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        has_one = bidder @ AuctionError::PublicKeyMismatch,
+        close = bidder
+    )]
+    pub bidder_state: Account<'info, BoyncSealedBid>,
+
+    /// CHECK: Auction authority, receives forfeited deposits.
+    #[account(mut, address = state.authority @ AuctionError::InvalidAuthority)]
+    pub authority: UncheckedAccount<'info>,
+
+    // Users and accounts in the system
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+}
+
 /*
  * [DEPRECATED]
  *
@@ -5,7 +5,8 @@ use anchor_lang::prelude::*;
  */
 #[error_code]
 pub enum AuctionError {
-    /// Invalid transition, auction state may only transition: Created -> Started -> Stopped
+    /// Invalid transition, auction state may only transition: Created -> Active -> Ended -> Settled,
+    /// or Created / Active -> Cancelled
     #[msg("Invalid auction state transition.")]
     AuctionTransitionInvalid,
 
@@ -48,4 +49,133 @@ pub enum AuctionError {
 
     #[msg("NumericalOverflow")]
     NumericalOverflow,
+
+    #[msg("Start price can't be lower than the floor price!")]
+    InvalidPriceRange,
+
+    #[msg("Auction has already been sold!")]
+    AuctionSold,
+
+    #[msg("Basis points can't exceed 10000.")]
+    InvalidBasisPoints,
+
+    #[msg("Reveal window has to end after the commit window.")]
+    InvalidRevealWindow,
+
+    #[msg("Reveal window is closed.")]
+    RevealWindowClosed,
+
+    #[msg("Bid has already been revealed!")]
+    BidAlreadyRevealed,
+
+    #[msg("Revealed bid does not match the commitment.")]
+    InvalidCommitment,
+
+    #[msg("Winner's bid account is missing.")]
+    BidRecordMissing,
+
+    #[msg("Winning bid is settled when claiming the auction.")]
+    WinnerMustClaim,
+
+    #[msg("Auction reserve price was not met.")]
+    ReserveNotMet,
+
+    #[msg("Auction reserve price was met, bids are not refundable.")]
+    ReserveMet,
+
+    #[msg("Buy now price can't be lower than the reserve price.")]
+    InvalidBuyNowPrice,
+
+    #[msg("Buy now is not available for this auction.")]
+    BuyNowUnavailable,
+
+    #[msg("Time extension window, amount and cap can't be negative, amount and cap can't exceed the longest auction duration.")]
+    InvalidTimeExtension,
+
+    #[msg("Bid increment schedule is invalid.")]
+    InvalidBidIncrement,
+
+    #[msg("Bid token cost and its rise must be greater than 0.")]
+    InvalidBidTokenCost,
+
+    #[msg("Auction can't be cancelled once it has bids.")]
+    AuctionHasBids,
+
+    #[msg("Auction has already started, its config can't be changed.")]
+    AuctionAlreadyStarted,
+
+    #[msg("Auction timestamps must be unix timestamps in milliseconds.")]
+    InvalidTimestamp,
+
+    #[msg("Auction has to end after it starts.")]
+    InvalidAuctionWindow,
+
+    #[msg("Auction duration is too short.")]
+    AuctionTooShort,
+
+    #[msg("Auction duration is too long.")]
+    AuctionTooLong,
+
+    #[msg("Auction can't start that far in the past.")]
+    AuctionStartInPast,
+
+    #[msg("Crank fee is above the maximum allowed.")]
+    InvalidCrankFee,
+
+    #[msg("Nothing to withdraw.")]
+    NothingToWithdraw,
+
+    #[msg("Creator accounts don't match the verified creators in the metadata.")]
+    CreatorMismatch,
+
+    #[msg("Seller splits must have a recipient each and add up to 100%.")]
+    InvalidSellerSplits,
+
+    #[msg("Auction proceeds were already paid out.")]
+    ProceedsAlreadyPaid,
+
+    #[msg("Payment window must be positive and at most the maximum auction duration.")]
+    InvalidPaymentWindow,
+
+    #[msg("Payment window is over.")]
+    PaymentWindowExpired,
+
+    #[msg("Winner can still pay, payment window is not over.")]
+    PaymentWindowOpen,
+
+    #[msg("Auction has no runner-up.")]
+    NoRunnerUp,
+
+    #[msg("Deposit is locked until the auction is settled.")]
+    DepositLocked,
+
+    #[msg("Proxy bid backs the highest bid until the auction is over.")]
+    ProxyBidActive,
+
+    #[msg("Bid gate needs a mint and a minimum amount, or a collection.")]
+    InvalidBidGate,
+
+    #[msg("Bidder doesn't hold the tokens this auction is gated on.")]
+    BidGateNotMet,
+
+    #[msg("Previous bidder account is needed to refund the outbid bid.")]
+    PreviousBidderMissing,
+
+    #[msg("Starting price must be greater than 0.")]
+    InvalidStartingPrice,
+
+    #[msg("Auction account already has the current layout.")]
+    AuctionAlreadyMigrated,
+
+    #[msg("Authority token account for the chest mint is needed to empty the chest.")]
+    ChestTokenAccountMissing,
+
+    #[msg("Account to close is not a token account.")]
+    NotATokenAccount,
+
+    #[msg("Only the program upgrade authority can initialize the config.")]
+    NotUpgradeAuthority,
+
+    #[msg("Program data account doesn't belong to this program.")]
+    InvalidProgramData,
 }
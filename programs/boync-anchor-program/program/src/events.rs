@@ -11,6 +11,9 @@ pub struct BoyncBidEvent {
     pub updated_bid_value: u64,
     pub updated_end_timestamp: i64,
     pub ts: i64,
+    pub bid_token_cost: u64, // v3 chest tokens charged for this bid
+    pub price_step: u64, // amount the bid value moved by
+    pub seq: u64, // per-bidder sequence number, 0 for bids keyed by a client timestamp
     #[index]
     pub label: String,
 }
@@ -22,6 +25,15 @@ pub struct BoyncInitializeEvent {
     pub label: String,
 }
 
+#[event]
+pub struct BoyncUpdateConfigEvent {
+    pub auction_pubkey: Pubkey,
+    pub start_auction_at: i64,
+    pub end_auction_at: i64,
+    #[index]
+    pub label: String,
+}
+
 #[event]
 pub struct BoyncEndEvent {
     pub auction_pubkey: Pubkey,
@@ -30,6 +42,28 @@ pub struct BoyncEndEvent {
     pub label: String,
 }
 
+#[event]
+pub struct BoyncProceedsEvent {
+    pub auction_pubkey: Pubkey,
+    pub seller_pubkey: Pubkey,
+    pub seller_amount: u64,
+    pub fee_amount: u64,
+    pub royalties: u64,
+    #[index]
+    pub label: String,
+}
+
+#[event]
+pub struct BoyncDefaultEvent {
+    pub auction_pubkey: Pubkey,
+    pub defaulter_pubkey: Pubkey,
+    pub unpaid_bid: u64,
+    pub offered_to: Option<Pubkey>, // runner-up, none when the authority reclaimed the token
+    pub slashed_deposit: u64, // defaulter's deposit, paid to the authority
+    #[index]
+    pub label: String,
+}
+
 #[event]
 pub struct BoyncStartEvent {
     pub auction_pubkey: Pubkey,
@@ -38,6 +72,15 @@ pub struct BoyncStartEvent {
     pub label: String
 }
 
+#[event]
+pub struct BoyncStateEvent {
+    pub auction_pubkey: Pubkey,
+    pub previous_auction_state: AuctionState,
+    pub updated_auction_state: AuctionState,
+    #[index]
+    pub label: String
+}
+
 #[event]
 pub struct BoyncClaimEvent {
     pub auction_pubkey: Pubkey,
@@ -45,3 +88,89 @@ pub struct BoyncClaimEvent {
     #[index]
     pub label: String
 }
+
+#[event]
+pub struct BoyncRefundEvent {
+    pub auction_pubkey: Pubkey,
+    pub bidder_pubkey: Pubkey,
+    pub refunded_value: u64,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncBuyEvent {
+    pub auction_pubkey: Pubkey,
+    pub buyer_pubkey: Pubkey,
+    pub price: u64,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncCommitEvent {
+    pub auction_pubkey: Pubkey,
+    pub bidder_pubkey: Pubkey,
+    pub deposit: u64,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncDepositEvent {
+    pub auction_pubkey: Pubkey,
+    pub bidder_pubkey: Pubkey,
+    pub deposit: u64,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncCancelEvent {
+    pub auction_pubkey: Pubkey,
+    pub authority_pubkey: Pubkey,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncSettleEvent {
+    pub auction_pubkey: Pubkey,
+    pub recipient_pubkey: Pubkey, // winner, or the authority when the auction wasn't sold
+    pub keeper_pubkey: Pubkey,
+    pub proceeds: u64,
+    pub crank_fee: u64,
+    pub protocol_fee: u64,
+    pub royalties: u64,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncWithdrawEvent {
+    pub auction_pubkey: Pubkey,
+    pub authority_pubkey: Pubkey,
+    pub mint: Pubkey, // system program for lamports
+    pub amount: u64,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncConfigEvent {
+    pub config_pubkey: Pubkey,
+    pub admin_pubkey: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub fee_bps: u16,
+    #[index]
+    pub label: String
+}
+
+#[event]
+pub struct BoyncReserveNotMetEvent {
+    pub auction_pubkey: Pubkey,
+    pub final_bid_value: u64,
+    pub reserve_price: u64,
+    #[index]
+    pub label: String
+}
@@ -5,16 +5,21 @@ pub mod constants;
 pub mod events;
 pub mod context;
 pub mod account;
+pub mod pricing;
 
 use anchor_lang::{
     prelude::*,
-    solana_program::{entrypoint::ProgramResult, native_token::LAMPORTS_PER_SOL},
     system_program,
+    Discriminator,
     { AnchorDeserialize, AnchorSerialize },
 };
 
 use anchor_spl::token::Transfer;
 
+use account::{
+    AuctionState, TimeExtensionPolicy, BidIncrement, BidCostRise, SellerSplits, ProceedsPayout, BidGate,
+    BoyncAuction2, BoyncAuction2Legacy, BoyncAuction3, BoyncAuction3Legacy,
+};
 use context::*;
 use events::*;
 use constants::*;
@@ -24,10 +29,42 @@ use utils::{
     token_transfer,
     assert_auction_active,
     assert_auction_active_v3,
+    assert_auction_active_english,
+    assert_auction_active_dutch,
+    assert_auction_active_sealed,
+    assert_reveal_active_sealed,
     assert_auction_over,
     assert_auction_over_v3,
-    process_time_extension,
-    process_time_extension_v3,
+    assert_auction_over_english,
+    assert_auction_over_dutch,
+    assert_auction_over_sealed,
+    process_time_extension_english,
+    refund_english_leader,
+    settle_buy_now,
+    emit_proxy_bid,
+    process_bid2,
+    process_bid3,
+    sync_scheduled_auction,
+    transition_auction_state,
+    assert_auction_timing,
+    assert_auction_config,
+    assert_auction_config_v3,
+    transfer_lamports_from_pda,
+    close_settled_auction2,
+    slash_defaulter_deposit,
+    close_token_account,
+    pay_with_protocol_fee,
+    pay_with_protocol_fee_from_pda,
+    pay_creator_royalties,
+    pay_creator_royalties_from_pda,
+    assert_bid_record_closable,
+    assert_payment_defaulted_v3,
+    flag_defaulter,
+    current_timestamp_ms,
+    sealed_bid_commitment,
+    assert_bid_gate,
+    read_legacy_account,
+    realloc_account,
 };
 
 
@@ -37,6 +74,55 @@ declare_id!("BJbJMqNcTPazBQTbSuPXVz2dZz7u1Vxb5w4TvACxEB3B");
 pub mod boync_anchor_program {
     use super::*;
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>, fee_bps: u16, fee_recipient: Pubkey) -> Result<()> {
+        // Can't take a fee above 100%.
+        require!(fee_bps <= MAX_BASIS_POINTS, AuctionError::InvalidBasisPoints);
+
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.fee_recipient = fee_recipient;
+        config.fee_bps = fee_bps;
+        config.bump = *ctx.bumps.get("config").unwrap();
+
+        emit!(BoyncConfigEvent {
+            config_pubkey: config.key(),
+            admin_pubkey: config.admin,
+            fee_recipient: config.fee_recipient,
+            fee_bps: config.fee_bps,
+            label: "config".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        fee_bps: u16,
+        fee_recipient: Pubkey,
+        new_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        // Can't take a fee above 100%.
+        require!(fee_bps <= MAX_BASIS_POINTS, AuctionError::InvalidBasisPoints);
+
+        let config = &mut ctx.accounts.config;
+        config.fee_recipient = fee_recipient;
+        config.fee_bps = fee_bps;
+
+        if let Some(new_admin) = new_admin {
+            config.admin = new_admin;
+        }
+
+        emit!(BoyncConfigEvent {
+            config_pubkey: config.key(),
+            admin_pubkey: config.admin,
+            fee_recipient: config.fee_recipient,
+            fee_bps: config.fee_bps,
+            label: "config".to_string(),
+        });
+
+        Ok(())
+    }
+
     pub fn initialize(
         ctx: Context<InitializeAuction2>,
         app_idx: i64,
@@ -47,19 +133,24 @@ pub mod boync_anchor_program {
     ) -> Result<()> {
         msg!("[BoyncProgram] Initializing new Boync Auction State");
 
+        assert_auction_timing(start_at, end_at)?;
+
         // let clock = Clock::get()?;
         let auction_state = &mut ctx.accounts.state;
 
         auction_state.id = app_idx; // App index is UnixTimestamp
         auction_state.start_auction_at = start_at;
         auction_state.end_auction_at = end_at;
-        auction_state.starting_price = (0.05 * (fp as f64)) as u64;
+        auction_state.starting_price = pricing::apply_bps(fp, STARTING_PRICE_BPS)?;
         auction_state.next_bid = auction_state.starting_price.clone();
         auction_state.authority = ctx.accounts.signer.key().clone();
         auction_state.treasury_mint = ctx.accounts.treasury_mint.key().clone();
         auction_state.treasury = ctx.accounts.treasury.key().clone();
         auction_state.bidders_chest = ctx.accounts.bidders_chest.key().clone();
         auction_state.bump = state_bump;
+        auction_state.state = AuctionState::create();
+        auction_state.bid_increment = BidIncrement::LEGACY_V2;
+        auction_state.time_extension = TimeExtensionPolicy::LEGACY;
 
         msg!("Initialized new Boync Auction State with treasury: {}", auction_state.treasury.key());
 
@@ -122,23 +213,54 @@ pub mod boync_anchor_program {
         state_bump: u8,
         fp: u64,
         start_at: i64,
-        end_at: i64
-    ) -> ProgramResult {
+        end_at: i64,
+        reserve_price: Option<u64>,
+        buy_now_price: Option<u64>,
+        time_extension: Option<TimeExtensionPolicy>,
+        bid_increment: Option<BidIncrement>,
+        crank_fee_bps: Option<u16>,
+        seller_splits: Option<SellerSplits>,
+        bid_gate: Option<BidGate>
+    ) -> Result<()> {
         msg!("[BoyncProgram] Initializing new Boync Auction State");
 
+        assert_auction_config(start_at, end_at, reserve_price, buy_now_price, time_extension, bid_increment)?;
+
+        // Can't pay keepers more than the maximum crank fee.
+        require!(crank_fee_bps.unwrap_or(0) <= MAX_CRANK_FEE_BPS, AuctionError::InvalidCrankFee);
+
+        if let Some(seller_splits) = seller_splits {
+            seller_splits.validate()?;
+        }
+
+        if let Some(bid_gate) = bid_gate {
+            bid_gate.validate()?;
+        }
+
         // let clock = Clock::get()?;
         let auction_state = &mut ctx.accounts.state;
 
         auction_state.id = app_idx; // App index is UnixTimestamp
         auction_state.start_auction_at = start_at;
         auction_state.end_auction_at = end_at;
-        auction_state.starting_price = (0.05 * (fp as f64)) as u64;
+        auction_state.starting_price = pricing::apply_bps(fp, STARTING_PRICE_BPS)?;
         auction_state.next_bid = auction_state.starting_price.clone();
         auction_state.authority = ctx.accounts.signer.key().clone();
         auction_state.treasury_mint = ctx.accounts.treasury_mint.key().clone();
         auction_state.treasury = ctx.accounts.treasury.key().clone();
         auction_state.bidders_chest = ctx.accounts.bidders_chest.key().clone();
         auction_state.bump = state_bump;
+        auction_state.state = AuctionState::create();
+        auction_state.highest_bid = 0;
+        auction_state.reserve_price = reserve_price;
+        auction_state.buy_now_price = buy_now_price;
+        auction_state.time_extension = time_extension.unwrap_or_default();
+        auction_state.extended_by = 0;
+        auction_state.bid_increment = bid_increment.unwrap_or(BidIncrement::LEGACY_V2);
+        auction_state.crank_fee_bps = crank_fee_bps.unwrap_or(0);
+        auction_state.seller_splits = seller_splits.unwrap_or_default();
+        auction_state.proceeds = None;
+        auction_state.bid_gate = bid_gate.unwrap_or_default();
 
         msg!("[BoyncDebug] Initialized with treasury: {}", auction_state.treasury.key());
 
@@ -189,10 +311,46 @@ pub mod boync_anchor_program {
         app_idx: i64,
         state_bump: u8,
         start_at: i64,
-        end_at: i64
-    ) -> ProgramResult {
+        end_at: i64,
+        reserve_price: Option<u64>,
+        buy_now_price: Option<u64>,
+        time_extension: Option<TimeExtensionPolicy>,
+        bid_increment: Option<BidIncrement>,
+        bid_token_cost: Option<u64>,
+        bid_cost_rise: Option<BidCostRise>,
+        seller_splits: Option<SellerSplits>,
+        payment_window: Option<i64>,
+        bid_deposit: Option<u64>,
+        bid_gate: Option<BidGate>
+    ) -> Result<()> {
         msg!("[BoyncDebug][v3] Initializing new Boync Auction State");
 
+        assert_auction_config_v3(
+            start_at,
+            end_at,
+            reserve_price,
+            buy_now_price,
+            time_extension,
+            bid_increment,
+            bid_token_cost,
+            bid_cost_rise
+        )?;
+
+        if let Some(seller_splits) = seller_splits {
+            seller_splits.validate()?;
+        }
+
+        if let Some(bid_gate) = bid_gate {
+            bid_gate.validate()?;
+        }
+
+        // Can't leave the winner without time to pay, nor lock the token for longer than an auction.
+        let payment_window = payment_window.unwrap_or(V3_PAYMENT_WINDOW);
+        require!(
+            payment_window > 0 && payment_window <= MAX_AUCTION_DURATION,
+            AuctionError::InvalidPaymentWindow
+        );
+
         // let clock = Clock::get()?;
         let auction_state = &mut ctx.accounts.state;
 
@@ -205,6 +363,23 @@ pub mod boync_anchor_program {
         auction_state.treasury = ctx.accounts.treasury.key().clone();
         auction_state.chest = ctx.accounts.chest.key().clone();
         auction_state.bump = state_bump;
+        auction_state.state = AuctionState::create();
+        auction_state.reserve_price = reserve_price;
+        auction_state.buy_now_price = buy_now_price;
+        auction_state.time_extension = time_extension.unwrap_or_default();
+        auction_state.extended_by = 0;
+        auction_state.bid_increment = bid_increment.unwrap_or(BidIncrement::LEGACY_V3);
+        auction_state.bid_token_cost = bid_token_cost.unwrap_or(V3_BID_TOKEN_COST);
+        auction_state.bid_cost_rise = bid_cost_rise;
+        auction_state.bid_count = 0;
+        auction_state.seller_splits = seller_splits.unwrap_or_default();
+        auction_state.payment_window = payment_window;
+        auction_state.payment_due_at = 0;
+        auction_state.runner_up = system_program::ID;
+        auction_state.runner_up_bid = 0;
+        auction_state.bid_deposit = bid_deposit.unwrap_or(0);
+        auction_state.open_deposits = 0;
+        auction_state.bid_gate = bid_gate.unwrap_or_default();
 
         msg!("[BoyncDebug][v3] Initialized with treasury: {}", auction_state.treasury.key());
 
@@ -250,6 +425,359 @@ pub mod boync_anchor_program {
         Ok(())
     }
 
+    pub fn initialize_english_auction(
+        ctx: Context<InitializeEnglishAuction>,
+        app_idx: i64,
+        state_bump: u8,
+        starting_price: u64,
+        min_bid_increment: u64,
+        start_at: i64,
+        end_at: i64,
+        buy_now_price: Option<u64>,
+        time_extension: Option<TimeExtensionPolicy>,
+        seller_splits: Option<SellerSplits>,
+        bid_gate: Option<BidGate>
+    ) -> Result<()> {
+        msg!("[BoyncDebug][english] Initializing new Boync Auction State");
+
+        assert_auction_timing(start_at, end_at)?;
+
+        // Can't start an English auction for free.
+        require!(starting_price > 0, AuctionError::InvalidStartingPrice);
+
+        // Can't outbid by nothing.
+        require!(min_bid_increment > 0, AuctionError::InvalidBidIncrement);
+
+        if let Some(time_extension) = time_extension {
+            time_extension.validate()?;
+        }
+
+        if let Some(seller_splits) = seller_splits {
+            seller_splits.validate()?;
+        }
+
+        if let Some(bid_gate) = bid_gate {
+            bid_gate.validate()?;
+        }
+
+        let auction_state = &mut ctx.accounts.state;
+
+        auction_state.id = app_idx; // App index is UnixTimestamp
+        auction_state.start_auction_at = start_at;
+        auction_state.end_auction_at = end_at;
+        auction_state.starting_price = starting_price;
+        auction_state.min_bid_increment = min_bid_increment;
+        auction_state.highest_bid = 0;
+        auction_state.buy_now_price = buy_now_price;
+        auction_state.time_extension = time_extension.unwrap_or_default();
+        auction_state.extended_by = 0;
+        auction_state.authority = ctx.accounts.signer.key().clone();
+        auction_state.treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        auction_state.treasury = ctx.accounts.treasury.key().clone();
+        auction_state.bump = state_bump;
+        auction_state.state = AuctionState::create();
+        auction_state.seller_splits = seller_splits.unwrap_or_default();
+        auction_state.proxy_max = 0;
+        auction_state.bid_gate = bid_gate.unwrap_or_default();
+
+        msg!("[BoyncDebug][english] Initialized with treasury: {}", auction_state.treasury.key());
+
+        let auction_state_clone = auction_state.to_account_info();
+
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.signer_token_account.to_account_info(),
+            token_owner: ctx.accounts.signer.to_account_info(),
+            destination: ctx.accounts.treasury.to_account_info(),
+            destination_owner: auction_state_clone,
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+            payer: ctx.accounts.signer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][english] Token transfered to treasury: {}", auction_state.treasury.key());
+
+        emit!(BoyncInitializeEvent {
+            auction_pubkey: auction_state.key(),
+            label: "initialize".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn initialize_dutch_auction(
+        ctx: Context<InitializeDutchAuction>,
+        app_idx: i64,
+        state_bump: u8,
+        start_price: u64,
+        floor_price: u64,
+        start_at: i64,
+        end_at: i64,
+        seller_splits: Option<SellerSplits>,
+        bid_gate: Option<BidGate>
+    ) -> Result<()> {
+        msg!("[BoyncDebug][dutch] Initializing new Boync Auction State");
+
+        require!(start_price >= floor_price, AuctionError::InvalidPriceRange);
+        assert_auction_timing(start_at, end_at)?;
+
+        if let Some(seller_splits) = seller_splits {
+            seller_splits.validate()?;
+        }
+
+        if let Some(bid_gate) = bid_gate {
+            bid_gate.validate()?;
+        }
+
+        let auction_state = &mut ctx.accounts.state;
+
+        auction_state.id = app_idx; // App index is UnixTimestamp
+        auction_state.start_auction_at = start_at;
+        auction_state.end_auction_at = end_at;
+        auction_state.start_price = start_price;
+        auction_state.floor_price = floor_price;
+        auction_state.sold_price = 0;
+        auction_state.authority = ctx.accounts.signer.key().clone();
+        auction_state.treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        auction_state.treasury = ctx.accounts.treasury.key().clone();
+        auction_state.bump = state_bump;
+        auction_state.state = AuctionState::create();
+        auction_state.seller_splits = seller_splits.unwrap_or_default();
+        auction_state.bid_gate = bid_gate.unwrap_or_default();
+
+        msg!("[BoyncDebug][dutch] Initialized with treasury: {}", auction_state.treasury.key());
+
+        let auction_state_clone = auction_state.to_account_info();
+
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.signer_token_account.to_account_info(),
+            token_owner: ctx.accounts.signer.to_account_info(),
+            destination: ctx.accounts.treasury.to_account_info(),
+            destination_owner: auction_state_clone,
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+            payer: ctx.accounts.signer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][dutch] Token transfered to treasury: {}", auction_state.treasury.key());
+
+        emit!(BoyncInitializeEvent {
+            auction_pubkey: auction_state.key(),
+            label: "initialize".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn initialize_sealed_auction(
+        ctx: Context<InitializeSealedAuction>,
+        app_idx: i64,
+        state_bump: u8,
+        starting_price: u64,
+        min_deposit: u64,
+        forfeit_bps: u16,
+        start_at: i64,
+        end_at: i64,
+        reveal_end_at: i64,
+        seller_splits: Option<SellerSplits>,
+        bid_gate: Option<BidGate>
+    ) -> Result<()> {
+        msg!("[BoyncDebug][sealed] Initializing new Boync Auction State");
+
+        require!(forfeit_bps <= MAX_BASIS_POINTS, AuctionError::InvalidBasisPoints);
+        require!(reveal_end_at > end_at, AuctionError::InvalidRevealWindow);
+        assert_auction_timing(start_at, end_at)?;
+
+        if let Some(seller_splits) = seller_splits {
+            seller_splits.validate()?;
+        }
+
+        if let Some(bid_gate) = bid_gate {
+            bid_gate.validate()?;
+        }
+
+        let auction_state = &mut ctx.accounts.state;
+
+        auction_state.id = app_idx; // App index is UnixTimestamp
+        auction_state.start_auction_at = start_at;
+        auction_state.end_auction_at = end_at;
+        auction_state.reveal_end_at = reveal_end_at;
+        auction_state.starting_price = starting_price;
+        auction_state.min_deposit = min_deposit;
+        auction_state.forfeit_bps = forfeit_bps;
+        auction_state.highest_bid = 0;
+        auction_state.second_bid = 0;
+        auction_state.authority = ctx.accounts.signer.key().clone();
+        auction_state.treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        auction_state.treasury = ctx.accounts.treasury.key().clone();
+        auction_state.bump = state_bump;
+        auction_state.state = AuctionState::create();
+        auction_state.seller_splits = seller_splits.unwrap_or_default();
+        auction_state.bid_gate = bid_gate.unwrap_or_default();
+
+        msg!("[BoyncDebug][sealed] Initialized with treasury: {}", auction_state.treasury.key());
+
+        let auction_state_clone = auction_state.to_account_info();
+
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.signer_token_account.to_account_info(),
+            token_owner: ctx.accounts.signer.to_account_info(),
+            destination: ctx.accounts.treasury.to_account_info(),
+            destination_owner: auction_state_clone,
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+            payer: ctx.accounts.signer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][sealed] Token transfered to treasury: {}", auction_state.treasury.key());
+
+        emit!(BoyncInitializeEvent {
+            auction_pubkey: auction_state.key(),
+            label: "initialize".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn update_auction_config(
+        ctx: Context<UpdateAuctionConfig>,
+        fp: u64,
+        start_at: i64,
+        end_at: i64,
+        reserve_price: Option<u64>,
+        buy_now_price: Option<u64>,
+        time_extension: Option<TimeExtensionPolicy>,
+        bid_increment: Option<BidIncrement>
+    ) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Config is locked once the Auction started.
+        require!(auction_state.state == AuctionState::Created, AuctionError::AuctionAlreadyStarted);
+
+        assert_auction_config(start_at, end_at, reserve_price, buy_now_price, time_extension, bid_increment)?;
+
+        auction_state.start_auction_at = start_at;
+        auction_state.end_auction_at = end_at;
+        auction_state.starting_price = pricing::apply_bps(fp, STARTING_PRICE_BPS)?;
+        auction_state.next_bid = auction_state.starting_price.clone();
+        auction_state.reserve_price = reserve_price;
+        auction_state.buy_now_price = buy_now_price;
+        auction_state.time_extension = time_extension.unwrap_or_default();
+        auction_state.bid_increment = bid_increment.unwrap_or(BidIncrement::LEGACY_V2);
+
+        emit!(BoyncUpdateConfigEvent {
+            auction_pubkey: auction_state.key(),
+            start_auction_at: auction_state.start_auction_at,
+            end_auction_at: auction_state.end_auction_at,
+            label: "config".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn update_auction_config3(
+        ctx: Context<UpdateAuctionConfig3>,
+        start_at: i64,
+        end_at: i64,
+        reserve_price: Option<u64>,
+        buy_now_price: Option<u64>,
+        time_extension: Option<TimeExtensionPolicy>,
+        bid_increment: Option<BidIncrement>,
+        bid_token_cost: Option<u64>,
+        bid_cost_rise: Option<BidCostRise>
+    ) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Config is locked once the Auction started.
+        require!(auction_state.state == AuctionState::Created, AuctionError::AuctionAlreadyStarted);
+
+        assert_auction_config_v3(
+            start_at,
+            end_at,
+            reserve_price,
+            buy_now_price,
+            time_extension,
+            bid_increment,
+            bid_token_cost,
+            bid_cost_rise
+        )?;
+
+        auction_state.start_auction_at = start_at;
+        auction_state.end_auction_at = end_at;
+        auction_state.reserve_price = reserve_price;
+        auction_state.buy_now_price = buy_now_price;
+        auction_state.time_extension = time_extension.unwrap_or_default();
+        auction_state.bid_increment = bid_increment.unwrap_or(BidIncrement::LEGACY_V3);
+        auction_state.bid_token_cost = bid_token_cost.unwrap_or(V3_BID_TOKEN_COST);
+        auction_state.bid_cost_rise = bid_cost_rise;
+
+        emit!(BoyncUpdateConfigEvent {
+            auction_pubkey: auction_state.key(),
+            start_auction_at: auction_state.start_auction_at,
+            end_auction_at: auction_state.end_auction_at,
+            label: "config".to_string(),
+        });
+
+        Ok(())
+    }
+
     /* Disabled as part of [BA-Program-5uJBi4jN][MVP] Remove BOYNC token GATE
     pub fn initialize(ctx: Context<InitializeAuction>, app_idx: i64, state_bump: u8) -> Result<()> {
         msg!("[BoyncProgram] Initializing new Boync Auction State");
@@ -336,10 +864,21 @@ pub mod boync_anchor_program {
     //     Ok(())
     // }
 
-    pub fn end(ctx: Context<EndAuction>, bidder_chest_bump: u8) -> Result<()> {
+    pub fn end<'info>(ctx: Context<'_, '_, '_, 'info, EndAuction<'info>>, bidder_chest_bump: u8) -> Result<()> {
         let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
         let bidders_chest = &mut ctx.accounts.bidders_chest;
-        let treasury = &mut ctx.accounts.treasury;
+
+        // Proceeds are only paid out once the auction is over.
+        require!(auction_state.state.is_over(), AuctionError::InvalidState);
+
+        // Bids are refunded through `refund_bid` when the reserve price was not met.
+        require!(auction_state.reserve_met(), AuctionError::ReserveNotMet);
+
+        // Can't pay out the bidders_chest twice.
+        require!(!auction_state.proceeds_paid(), AuctionError::ProceedsAlreadyPaid);
 
         /* Build bidders_chest PDA to sign transaction */
         // let bump = *ctx.bumps.get("wallet").unwrap();
@@ -353,55 +892,276 @@ pub mod boync_anchor_program {
         ];
         let signer_seeds = &[&seeds[..]];
 
+        /* Pay the bidders_chest to the authority, less creator royalties and the protocol fee */
         let total_lamports: u64 = bidders_chest.lamports();
+        let royalties = pay_creator_royalties(
+            &ctx.accounts.system_program.to_account_info(),
+            &bidders_chest.to_account_info(),
+            &ctx.accounts.metadata.to_account_info(),
+            &auction_state.treasury_mint,
+            ctx.remaining_accounts,
+            total_lamports,
+            signer_seeds
+        )?;
 
-        /* transfer 75% of bidders_chest to authority account */
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: bidders_chest.to_account_info(),
-                    to: ctx.accounts.authority.to_account_info(),
-                },
-                signer_seeds
-            ),
-            ((total_lamports as f64) * 0.75) as u64
+        let (seller_amount, fee_amount) = pay_with_protocol_fee(
+            &ctx.accounts.system_program.to_account_info(),
+            &bidders_chest.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.seller_splits,
+            ctx.remaining_accounts,
+            &ctx.accounts.fee_recipient.to_account_info(),
+            &ctx.accounts.config,
+            pricing::checked_sub(total_lamports, royalties)?,
+            signer_seeds
+        )?;
+
+        auction_state.proceeds = Some(ProceedsPayout {
+            seller: seller_amount,
+            protocol_fee: fee_amount,
+            royalties,
+            crank_fee: 0,
+        });
+
+        emit!(BoyncProceedsEvent {
+            auction_pubkey: auction_state.key(),
+            seller_pubkey: auction_state.authority.clone(),
+            seller_amount,
+            fee_amount,
+            royalties,
+            label: "proceeds".to_string(),
+        });
+
+        emit!(BoyncEndEvent {
+            auction_pubkey: auction_state.key(),
+            updated_end_timestamp: auction_state.end_auction_at,
+            label: "end".to_string(),
+        });
+
+        close_settled_auction2(
+            auction_state,
+            &ctx.accounts.bidders_chest.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
+    /// [DEPRECATED] Bid records keyed by a client timestamp, kept while clients migrate to `place_bid2`.
+    /// `ts` is only used as the record seed, the stored timestamp comes from the clock.
+    pub fn update_auction2<'info>(ctx: Context<'_, '_, '_, 'info, UpdateAuction2<'info>>, _ts: i64) -> Result<()> {
+        // Can't bid without holding what the auction is gated on.
+        assert_bid_gate(&ctx.accounts.state.bid_gate, ctx.accounts.bidder.key, ctx.remaining_accounts)?;
+
+        process_bid2(
+            &mut ctx.accounts.state,
+            &mut ctx.accounts.bidder_state,
+            &ctx.accounts.bidder,
+            &ctx.accounts.bidders_chest,
+            &ctx.accounts.system_program,
+            0,
+        )
+    }
+
+    pub fn place_bid2<'info>(ctx: Context<'_, '_, '_, 'info, PlaceBid2<'info>>) -> Result<()> {
+        // Can't bid without holding what the auction is gated on.
+        assert_bid_gate(&ctx.accounts.state.bid_gate, ctx.accounts.bidder.key, ctx.remaining_accounts)?;
+
+        let seq = ctx.accounts.bidder_counter.next_seq();
+
+        let bidder_counter = &mut ctx.accounts.bidder_counter;
+        bidder_counter.auction = ctx.accounts.state.key();
+        bidder_counter.bidder = ctx.accounts.bidder.key.clone();
+        bidder_counter.bid_count = seq;
+
+        process_bid2(
+            &mut ctx.accounts.state,
+            &mut ctx.accounts.bidder_state,
+            &ctx.accounts.bidder,
+            &ctx.accounts.bidders_chest,
+            &ctx.accounts.system_program,
+            seq,
+        )
+    }
+
+    pub fn migrate_auction2(ctx: Context<MigrateAuction2>) -> Result<()> {
+        let state = ctx.accounts.state.to_account_info();
+
+        let legacy: BoyncAuction2Legacy = read_legacy_account(
+            &state,
+            BoyncAuction2::discriminator(),
+            BoyncAuction2Legacy::AUCTION_SIZE
+        )?;
+
+        // Can't migrate an Auction you're not the authority of.
+        require!(legacy.authority == ctx.accounts.authority.key(), AuctionError::InvalidAuthority);
+
+        realloc_account(
+            &state,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + BoyncAuction2::AUCTION_SIZE
+        )?;
+
+        let auction_state = legacy.migrate()?;
+        auction_state.try_serialize(&mut &mut state.try_borrow_mut_data()?[..])?;
+
+        msg!("[BoyncDebug][migrate_auction2] Migrated auction: {}", state.key());
+
+        Ok(())
+    }
+
+    pub fn migrate_auction3(ctx: Context<MigrateAuction3>) -> Result<()> {
+        let state = ctx.accounts.state.to_account_info();
+
+        let legacy: BoyncAuction3Legacy = read_legacy_account(
+            &state,
+            BoyncAuction3::discriminator(),
+            BoyncAuction3Legacy::AUCTION_SIZE
+        )?;
+
+        // Can't migrate an Auction you're not the authority of.
+        require!(legacy.authority == ctx.accounts.authority.key(), AuctionError::InvalidAuthority);
+
+        realloc_account(
+            &state,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + BoyncAuction3::AUCTION_SIZE
         )?;
 
-        /* transfer rest (25%) of bidders_chest to treasury account */
+        let auction_state = legacy.migrate();
+        auction_state.try_serialize(&mut &mut state.try_borrow_mut_data()?[..])?;
+
+        msg!("[BoyncDebug][migrate_auction3] Migrated auction: {}", state.key());
+
+        Ok(())
+    }
+
+    pub fn refund_bid(ctx: Context<RefundBid>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't refund on an Auction that is ongoing.
+        assert_auction_over(&auction_state)?;
+        require!(auction_state.state.is_over(), AuctionError::InvalidState);
+
+        // Bids are only refunded when the reserve price was not met.
+        require!(!auction_state.reserve_met(), AuctionError::ReserveMet);
+
+        let bump = *ctx.bumps.get("bidders_chest").unwrap();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            CHEST_SEED,
+            auction_auth.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let bid_value = ctx.accounts.bidder_state.bid_value;
+
         system_program::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.system_program.to_account_info(),
                 system_program::Transfer {
-                    from: bidders_chest.to_account_info(),
-                    to: treasury.to_account_info(),
+                    from: ctx.accounts.bidders_chest.to_account_info(),
+                    to: ctx.accounts.bidder.to_account_info(),
                 },
                 signer_seeds
             ),
-            bidders_chest.lamports().clone()
+            bid_value
         )?;
 
-        emit!(BoyncEndEvent {
+        // `bidder_state` is closed back to the bidder, it can't be refunded twice.
+        emit!(BoyncRefundEvent {
             auction_pubkey: auction_state.key(),
-            updated_end_timestamp: auction_state.end_auction_at,
-            label: "end".to_string(),
+            bidder_pubkey: ctx.accounts.bidder.key(),
+            refunded_value: bid_value,
+            label: "refund".to_string(),
         });
 
+        close_settled_auction2(
+            auction_state,
+            &ctx.accounts.bidders_chest.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn close_bid_record(ctx: Context<CloseBidRecord>) -> Result<()> {
+        // Can't reclaim rent while the bid record is still needed.
+        assert_bid_record_closable(&ctx.accounts.auction.to_account_info())?;
+
+        // `bidder_state` is closed back to the bidder by anchor.
         Ok(())
     }
 
-    pub fn update_auction2(ctx: Context<UpdateAuction2>, ts: i64) -> Result<()> {
+    /// [DEPRECATED] Bid records keyed by a client timestamp, kept while clients migrate to `place_bid3`.
+    /// `ts` is only used as the record seed, the stored timestamp comes from the clock.
+    pub fn update_auction3<'info>(ctx: Context<'_, '_, '_, 'info, UpdateAuction3<'info>>, _ts: i64) -> Result<()> {
+        // Can't bid without holding what the auction is gated on.
+        assert_bid_gate(&ctx.accounts.state.bid_gate, ctx.accounts.bidder.key, ctx.remaining_accounts)?;
+
+        process_bid3(
+            &mut ctx.accounts.state,
+            &mut ctx.accounts.bidder_state,
+            &mut ctx.accounts.deposit,
+            &ctx.accounts.bidder,
+            &ctx.accounts.bidder_token_account,
+            &ctx.accounts.chest,
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            0,
+        )
+    }
+
+    pub fn place_bid3<'info>(ctx: Context<'_, '_, '_, 'info, PlaceBid3<'info>>) -> Result<()> {
+        // Can't bid without holding what the auction is gated on.
+        assert_bid_gate(&ctx.accounts.state.bid_gate, ctx.accounts.bidder.key, ctx.remaining_accounts)?;
+
+        let seq = ctx.accounts.bidder_counter.next_seq();
+
+        let bidder_counter = &mut ctx.accounts.bidder_counter;
+        bidder_counter.auction = ctx.accounts.state.key();
+        bidder_counter.bidder = ctx.accounts.bidder.key.clone();
+        bidder_counter.bid_count = seq;
+
+        process_bid3(
+            &mut ctx.accounts.state,
+            &mut ctx.accounts.bidder_state,
+            &mut ctx.accounts.deposit,
+            &ctx.accounts.bidder,
+            &ctx.accounts.bidder_token_account,
+            &ctx.accounts.chest,
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            seq,
+        )
+    }
+
+    /// `ts` is deprecated and ignored, kept so existing clients don't break.
+    /// Bid records are keyed by the bidder's sequence number, the stored timestamp comes from the clock.
+    pub fn place_bid<'info>(ctx: Context<'_, '_, '_, 'info, PlaceBid<'info>>, _ts: i64, amount: u64) -> Result<()> {
+        // Can't bid without holding what the auction is gated on.
+        assert_bid_gate(&ctx.accounts.state.bid_gate, ctx.accounts.bidder.key, ctx.remaining_accounts)?;
+
+        let seq = ctx.accounts.bidder_counter.next_seq();
+
+        let bidder_counter = &mut ctx.accounts.bidder_counter;
+        bidder_counter.auction = ctx.accounts.state.key();
+        bidder_counter.bidder = ctx.accounts.bidder.key.clone();
+        bidder_counter.bid_count = seq;
+
         let auction_state = &mut ctx.accounts.state;
-        // let clock = Clock::get()?;
 
-        // Can't bid on an Auction that is expired.
-        // require!(!auction_state.ended(clock.unix_timestamp)?,
-        //     AuctionError::AuctionExpired);
-        assert_auction_active(&auction_state)?;
+        sync_scheduled_auction(auction_state)?;
 
-        // Can't bid on an Auction that is not started.
-        // require!(auction_state.state == AuctionState::Started,
-        //     AuctionError::InvalidState);
+        // Can't bid on an Auction that is not running.
+        assert_auction_active_english(&auction_state)?;
 
         // Can't bid on an Auction that was already claimed.
         require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
@@ -418,67 +1178,230 @@ pub mod boync_anchor_program {
             AuctionError::AuctionAlreadyLastBidder
         );
 
-        // Just transfer SPL Token to bidders_chest
-        // let _bump = *ctx.bumps.get("auction").unwrap();
-        // let bump = auction_state.bump;
-        let auction_auth = auction_state.authority.clone();
-        let treasury_mint = auction_state.treasury_mint.key().clone();
-        let app_idx_bytes = auction_state.id.to_le_bytes();
-        let seeds = &[
-            AUCTION_SEED,
-            auction_auth.as_ref(),
-            treasury_mint.as_ref(),
-            app_idx_bytes.as_ref(),
-            &[auction_state.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        // Bid has to at least match the starting price / highest bid + increment
+        require!(amount >= auction_state.min_next_bid()?, AuctionError::BidTooSmall);
+
+        /* Store bid state, the value is only recorded once the bid is escrowed */
+        let bidder_state = &mut ctx.accounts.bidder_state;
+        bidder_state.auction = auction_state.key();
+        bidder_state.bidder = ctx.accounts.bidder.key.clone();
+        bidder_state.ts = current_timestamp_ms()?;
+
+        let leader = auction_state.last_bidder.clone();
+        let leader_max = auction_state.proxy_max;
+        let previous_bid = auction_state.highest_bid;
+        process_time_extension_english(auction_state)?;
+
+        emit!(BoyncBidEvent {
+            auction_pubkey: auction_state.key(),
+            bidder_pubkey: ctx.accounts.bidder.key.clone(),
+            updated_bid_value: amount,
+            updated_end_timestamp: auction_state.end_auction_at,
+            label: "bid".to_string(),
+            ts: bidder_state.ts,
+            bid_token_cost: 0,
+            price_step: pricing::checked_sub(amount, previous_bid)?,
+            seq,
+        });
+
+        if leader_max >= amount {
+            /* Leader's proxy answers with the minimum outbidding bid, up to its maximum, nothing is escrowed for the bidder */
+            let counter_bid = pricing::checked_add(amount, auction_state.min_bid_increment)?.min(leader_max);
+
+            transfer_lamports_from_pda(
+                &ctx.accounts.leader_proxy.to_account_info(),
+                &auction_state.to_account_info(),
+                pricing::checked_sub(counter_bid, previous_bid)?
+            )?;
+
+            auction_state.highest_bid = counter_bid;
+            emit_proxy_bid(&auction_state, leader, counter_bid, amount)?;
+
+            return Ok(());
+        }
 
+        /* Escrow the new bid in the auction state */
         let transfer_instruction = anchor_lang::system_program::Transfer {
             from: ctx.accounts.bidder.to_account_info(),
-            to: ctx.accounts.bidders_chest.to_account_info(),
+            to: auction_state.to_account_info(),
         };
 
-        let cpi_ctx = CpiContext::new_with_signer(
+        let cpi_ctx = CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
-            transfer_instruction,
-            signer_seeds
+            transfer_instruction
         );
 
-        anchor_lang::system_program::transfer(cpi_ctx, auction_state.next_bid.clone())?;
+        anchor_lang::system_program::transfer(cpi_ctx, amount)?;
 
-        /* Store bid state */
-        let bidder_state = &mut ctx.accounts.bidder_state;
-        bidder_state.auction = auction_state.key();
-        bidder_state.bidder = ctx.accounts.bidder.key.clone();
-        bidder_state.bid_value = auction_state.next_bid;
-        bidder_state.ts = ts;
+        bidder_state.bid_value = amount;
 
+        /* Refund the outbid user */
+        let previous_bidder = ctx.accounts.previous_bidder
+            .as_ref()
+            .map(|previous_bidder| previous_bidder.to_account_info());
+
+        refund_english_leader(
+            &auction_state,
+            previous_bidder.as_ref(),
+            &ctx.accounts.leader_proxy.to_account_info(),
+        )?;
+
+        auction_state.highest_bid = amount;
         auction_state.last_bidder = ctx.accounts.bidder.key.clone();
-        // auction_state.end_auction_at += 60 * MS_IN_SEC; // Add 60 seconds to countdown
-        process_time_extension(auction_state)?;
-        auction_state.next_bid = (1.05 * (auction_state.next_bid as f64)) as u64;
+        auction_state.proxy_max = 0;
 
-        emit!(BoyncBidEvent {
+        Ok(())
+    }
+
+    pub fn place_proxy_bid<'info>(ctx: Context<'_, '_, '_, 'info, PlaceProxyBid<'info>>, max_bid: u64) -> Result<()> {
+        // Can't bid without holding what the auction is gated on.
+        assert_bid_gate(&ctx.accounts.state.bid_gate, ctx.accounts.bidder.key, ctx.remaining_accounts)?;
+
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't bid on an Auction that is not running.
+        assert_auction_active_english(&auction_state)?;
+
+        // Can't bid on an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+
+        // Can't bid on an Auction you're the authority of.
+        require!(
+            auction_state.authority.key() != ctx.accounts.bidder.key(),
+            AuctionError::AuctionAuthorityBid
+        );
+
+        let proxy = &mut ctx.accounts.proxy;
+
+        // Can only raise a maximum.
+        require!(max_bid > proxy.max_bid, AuctionError::BidTooSmall);
+
+        /* Escrow the raise in the proxy account */
+        let transfer_instruction = anchor_lang::system_program::Transfer {
+            from: ctx.accounts.bidder.to_account_info(),
+            to: proxy.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_instruction
+        );
+
+        anchor_lang::system_program::transfer(cpi_ctx, pricing::checked_sub(max_bid, proxy.max_bid)?)?;
+
+        proxy.auction = auction_state.key();
+        proxy.bidder = ctx.accounts.bidder.key.clone();
+        proxy.max_bid = max_bid;
+
+        /* Leader only raises their maximum, the proxy backs their bid from now on */
+        if auction_state.last_bidder.key() == ctx.accounts.bidder.key() {
+            require!(max_bid > auction_state.highest_bid, AuctionError::BidTooSmall);
+            auction_state.proxy_max = max_bid;
+            return Ok(());
+        }
+
+        // Maximum has to at least match the starting price / highest bid + increment
+        require!(max_bid >= auction_state.min_next_bid()?, AuctionError::BidTooSmall);
+
+        let leader = auction_state.last_bidder.clone();
+        let leader_max = auction_state.proxy_max;
+        let previous_bid = auction_state.highest_bid;
+        process_time_extension_english(auction_state)?;
+
+        if leader_max >= max_bid {
+            /* Leader's proxy wins, ties go to the earlier proxy */
+            let counter_bid = pricing::checked_add(max_bid, auction_state.min_bid_increment)?.min(leader_max);
+
+            transfer_lamports_from_pda(
+                &ctx.accounts.leader_proxy.to_account_info(),
+                &auction_state.to_account_info(),
+                pricing::checked_sub(counter_bid, previous_bid)?
+            )?;
+
+            auction_state.highest_bid = counter_bid;
+
+            emit_proxy_bid(&auction_state, ctx.accounts.bidder.key(), max_bid, previous_bid)?;
+            emit_proxy_bid(&auction_state, leader, counter_bid, max_bid)?;
+
+            return Ok(());
+        }
+
+        /* Bidder's proxy wins, at the minimum outbidding bid */
+        let mut bid = auction_state.min_next_bid()?;
+        let mut outbid_value = previous_bid;
+
+        if leader_max > 0 {
+            // Leader's proxy bids up to its maximum first.
+            if leader_max > previous_bid {
+                emit_proxy_bid(&auction_state, leader, leader_max, previous_bid)?;
+            }
+
+            bid = pricing::checked_add(leader_max, auction_state.min_bid_increment)?.min(max_bid);
+            outbid_value = leader_max;
+        }
+
+        let previous_bidder = ctx.accounts.previous_bidder
+            .as_ref()
+            .map(|previous_bidder| previous_bidder.to_account_info());
+
+        refund_english_leader(
+            &auction_state,
+            previous_bidder.as_ref(),
+            &ctx.accounts.leader_proxy.to_account_info(),
+        )?;
+
+        transfer_lamports_from_pda(
+            &ctx.accounts.proxy.to_account_info(),
+            &auction_state.to_account_info(),
+            bid
+        )?;
+
+        auction_state.highest_bid = bid;
+        auction_state.last_bidder = ctx.accounts.bidder.key.clone();
+        auction_state.proxy_max = max_bid;
+
+        emit_proxy_bid(&auction_state, ctx.accounts.bidder.key(), bid, outbid_value)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_proxy_bid(ctx: Context<WithdrawProxyBid>) -> Result<()> {
+        let auction_state = &ctx.accounts.state;
+        let clock = Clock::get()?;
+
+        // Can't take back the escrow backing the highest bid while bidding is open.
+        let leading = auction_state.last_bidder.key() == ctx.accounts.bidder.key()
+            && auction_state.proxy_max > 0;
+        require!(
+            !leading || auction_state.claimed == 1 || auction_state.ended(clock.unix_timestamp)?,
+            AuctionError::ProxyBidActive
+        );
+
+        // Escrow and the rent are returned by closing `proxy`.
+        emit!(BoyncRefundEvent {
             auction_pubkey: auction_state.key(),
-            bidder_pubkey: auction_state.last_bidder.clone(),
-            updated_bid_value: auction_state.next_bid.clone(),
-            updated_end_timestamp: auction_state.end_auction_at,
-            label: "bid".to_string(),
-            ts: ts,
+            bidder_pubkey: ctx.accounts.bidder.key(),
+            refunded_value: ctx.accounts.proxy.to_account_info().lamports(),
+            label: "refund_proxy".to_string(),
         });
 
         Ok(())
     }
 
-    pub fn update_auction3(ctx: Context<UpdateAuction3>, ts: i64) -> Result<()> {
+    pub fn commit_bid<'info>(ctx: Context<'_, '_, '_, 'info, CommitBid<'info>>, commitment: [u8; 32], deposit: u64) -> Result<()> {
+        // Can't bid without holding what the auction is gated on.
+        assert_bid_gate(&ctx.accounts.state.bid_gate, ctx.accounts.bidder.key, ctx.remaining_accounts)?;
+
         let auction_state = &mut ctx.accounts.state;
-        // let clock = Clock::get()?;
 
-        // Can't bid on an Auction that is ended.
-        assert_auction_active_v3(&auction_state)?;
+        sync_scheduled_auction(auction_state)?;
 
-        // Can't bid on an Auction that was already claimed.
-        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        let clock = Clock::get()?;
+
+        // Can only commit while the commit window is open.
+        assert_auction_active_sealed(&auction_state)?;
 
         // Can't bid on an Auction you're the authority of.
         require!(
@@ -486,17 +1409,1396 @@ pub mod boync_anchor_program {
             AuctionError::AuctionAuthorityBid
         );
 
-        // Can't bid on an Auction if you're already Last Bidder
-        require!(
-            auction_state.last_bidder.key() != ctx.accounts.bidder.key(),
-            AuctionError::AuctionAlreadyLastBidder
-        );
+        require!(deposit >= auction_state.min_deposit, AuctionError::BidTooSmall);
+
+        /* Lock the deposit in the bidder's sealed bid account */
+        let transfer_instruction = anchor_lang::system_program::Transfer {
+            from: ctx.accounts.bidder.to_account_info(),
+            to: ctx.accounts.bidder_state.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_instruction
+        );
+
+        anchor_lang::system_program::transfer(cpi_ctx, deposit)?;
+
+        let bidder_state = &mut ctx.accounts.bidder_state;
+        bidder_state.auction = auction_state.key();
+        bidder_state.bidder = ctx.accounts.bidder.key.clone();
+        bidder_state.commitment = commitment;
+        bidder_state.deposit = deposit;
+        bidder_state.bid_value = 0;
+        bidder_state.revealed = 0;
+        bidder_state.ts = clock.unix_timestamp * MS_IN_SEC;
+
+        emit!(BoyncCommitEvent {
+            auction_pubkey: auction_state.key(),
+            bidder_pubkey: bidder_state.bidder.clone(),
+            deposit: deposit,
+            label: "commit".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn reveal_bid(ctx: Context<RevealBid>, amount: u64, salt: [u8; 32]) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        let clock = Clock::get()?;
+
+        // Can only reveal once the commit window is over and before the reveal window ends.
+        assert_reveal_active_sealed(&auction_state)?;
+
+        require!(ctx.accounts.bidder_state.revealed == 0, AuctionError::BidAlreadyRevealed);
+        require!(
+            sealed_bid_commitment(amount, &salt) == ctx.accounts.bidder_state.commitment,
+            AuctionError::InvalidCommitment
+        );
+        require!(amount >= auction_state.starting_price, AuctionError::BidTooSmall);
+
+        /* Top up the escrow so it covers the revealed amount */
+        let deposit = ctx.accounts.bidder_state.deposit;
+        if amount > deposit {
+            let transfer_instruction = anchor_lang::system_program::Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.bidder_state.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                transfer_instruction
+            );
+
+            anchor_lang::system_program::transfer(cpi_ctx, pricing::checked_sub(amount, deposit)?)?;
+        }
+
+        let bidder_state = &mut ctx.accounts.bidder_state;
+        bidder_state.deposit = deposit.max(amount);
+        bidder_state.bid_value = amount;
+        bidder_state.revealed = 1;
+
+        /* Keep track of the two highest revealed bids, ties go to the first reveal */
+        if amount > auction_state.highest_bid {
+            auction_state.second_bid = auction_state.highest_bid;
+            auction_state.highest_bid = amount;
+            auction_state.last_bidder = bidder_state.bidder.clone();
+        } else if amount > auction_state.second_bid {
+            auction_state.second_bid = amount;
+        }
+
+        emit!(BoyncBidEvent {
+            auction_pubkey: auction_state.key(),
+            bidder_pubkey: bidder_state.bidder.clone(),
+            updated_bid_value: amount,
+            updated_end_timestamp: auction_state.reveal_end_at,
+            label: "reveal".to_string(),
+            ts: clock.unix_timestamp * MS_IN_SEC,
+            bid_token_cost: 0,
+            price_step: 0,
+            seq: 0,
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_sealed_bid(ctx: Context<WithdrawSealedBid>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        let bidder_state = &ctx.accounts.bidder_state;
+
+        // Deposits are locked until the reveal window is over.
+        assert_auction_over_sealed(&auction_state)?;
+        require!(auction_state.state.is_over(), AuctionError::InvalidState);
+
+        // Winner's deposit is settled in `claim_rewards_sealed`.
+        require!(
+            auction_state.last_bidder.key() != ctx.accounts.bidder.key(),
+            AuctionError::WinnerMustClaim
+        );
+
+        /* Unrevealed deposits are partially forfeited to the authority */
+        let mut forfeited: u64 = 0;
+        if bidder_state.revealed == 0 {
+            forfeited = pricing::apply_bps(bidder_state.deposit, auction_state.forfeit_bps)?;
+
+            transfer_lamports_from_pda(
+                &bidder_state.to_account_info(),
+                &ctx.accounts.authority.to_account_info(),
+                forfeited
+            )?;
+        }
+
+        // Rest of the deposit and the rent are returned by closing `bidder_state`.
+        emit!(BoyncRefundEvent {
+            auction_pubkey: auction_state.key(),
+            bidder_pubkey: ctx.accounts.bidder.key(),
+            refunded_value: pricing::checked_sub(bidder_state.deposit, forfeited)?,
+            label: "refund".to_string(),
+        });
+
+        Ok(())
+    }
+
+    /// Bid
+    /* 
+    pub fn bid(ctx: Context<UpdateAuction>, amount: u64) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+        let clock = Clock::get()?;
+
+        // Can't bid on an Auction that is expired.
+        require!(!auction_state.ended(clock.unix_timestamp)?,
+            AuctionError::AuctionExpired);
+    
+        // Can't bid on an Auction that is not started.
+        require!(auction_state.state == AuctionState::Active,
+            AuctionError::InvalidState);
+
+        // Just transfer SPL Token to bidders_chest
+        // let _bump = *ctx.bumps.get("auction").unwrap();
+        // let bump = auction_state.bump;
+        let auction_auth = auction_state.authority.clone();
+        let treasury_mint = auction_state.treasury_mint.key().clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Token program instruction to send SPL token.
+        let transfer_instruction = Transfer {
+            from:       ctx.accounts.signer_ata.to_account_info(),
+            to:         ctx.accounts.bidders_chest.to_account_info(),
+            authority:  ctx.accounts.bidder.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_instruction,
+            signer_seeds
+        );
+
+        anchor_spl::token::transfer(cpi_ctx, amount)?;
+
+        auction_state.last_bidder = ctx.accounts.bidder.key.clone();
+        auction_state.end_auction_at += 60 * MS_IN_SEC; // Add 60 seconds to countdown
+        auction_state.tokens_spent += 1;
+
+        Ok(())
+    }
+    */
+    pub fn buy_dutch<'info>(ctx: Context<'_, '_, '_, 'info, BuyDutch<'info>>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        let clock = Clock::get()?;
+
+        // Can't buy on an Auction that is not running.
+        assert_auction_active_dutch(&auction_state)?;
+
+        // Can't buy on an Auction that was already sold.
+        require!(auction_state.claimed == 0, AuctionError::AuctionSold);
+
+        // Can't buy on an Auction you're the authority of.
+        require!(
+            auction_state.authority.key() != ctx.accounts.buyer.key(),
+            AuctionError::AuctionAuthorityBid
+        );
+
+        // Can't buy without holding what the auction is gated on.
+        let payout_accounts = assert_bid_gate(&auction_state.bid_gate, ctx.accounts.buyer.key, ctx.remaining_accounts)?;
+
+        let price = auction_state.current_price(clock.unix_timestamp * MS_IN_SEC)?;
+
+        /* Buyer pays the current price straight to the authority, less creator royalties and the protocol fee */
+        let royalties = pay_creator_royalties(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.treasury_mint.key(),
+            payout_accounts,
+            price,
+            &[]
+        )?;
+
+        pay_with_protocol_fee(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.seller_splits,
+            payout_accounts,
+            &ctx.accounts.fee_recipient.to_account_info(),
+            &ctx.accounts.config,
+            pricing::checked_sub(price, royalties)?,
+            &[]
+        )?;
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        /* Then we transfer the NFT to the buyer */
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.buyer_token_account.to_account_info(),
+            destination_owner: ctx.accounts.buyer.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.buyer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][buy_dutch] treasury transfered token.");
+
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        auction_state.sold_price = price;
+        auction_state.last_bidder = ctx.accounts.buyer.key.clone();
+        auction_state.claimed = 1;
+
+        /* Sold before end_auction_at, the auction ends and settles right away */
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::end,
+            "end"
+        )?;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        emit!(BoyncBuyEvent {
+            auction_pubkey: auction_state.key(),
+            buyer_pubkey: auction_state.last_bidder.clone(),
+            price: price,
+            label: "buy".to_string(),
+        });
+
+        emit!(BoyncClaimEvent {
+            auction_pubkey: auction_state.key(),
+            claimed: auction_state.claimed,
+            label: "claim".to_string(),
+        });
+
+        /* Price was paid straight to the authority, nothing is left on the state */
+        auction_state.close(ctx.accounts.authority.to_account_info())?;
+
+        Ok(())
+    }
+
+    pub fn buy_now<'info>(ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't buy on an Auction that is not running.
+        assert_auction_active(&auction_state)?;
+
+        // Can't buy on an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+
+        // Can't buy once bidding has reached the buy now price.
+        require!(auction_state.buy_now_available(), AuctionError::BuyNowUnavailable);
+
+        // Can't buy on an Auction you're the authority of.
+        require!(
+            auction_state.authority.key() != ctx.accounts.buyer.key(),
+            AuctionError::AuctionAuthorityBid
+        );
+
+        // Can't buy without holding what the auction is gated on.
+        let payout_accounts = assert_bid_gate(&auction_state.bid_gate, ctx.accounts.buyer.key, ctx.remaining_accounts)?;
+
+        let price = auction_state.buy_now_price.unwrap();
+
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.buyer_token_account.to_account_info(),
+            destination_owner: ctx.accounts.buyer.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.buyer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        auction_state.state = settle_buy_now(
+            transfer_accounts,
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.fee_recipient.to_account_info(),
+            &ctx.accounts.config,
+            &auction_state.seller_splits,
+            payout_accounts,
+            auction_state.id,
+            auction_state.bump,
+            auction_state.state,
+            price
+        )?;
+
+        auction_state.highest_bid = price;
+        auction_state.last_bidder = ctx.accounts.buyer.key.clone();
+        auction_state.claimed = 1;
+
+        Ok(())
+    }
+
+    pub fn buy_now3<'info>(ctx: Context<'_, '_, '_, 'info, BuyNow3<'info>>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't buy on an Auction that is not running.
+        assert_auction_active_v3(&auction_state)?;
+
+        // Can't buy on an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+
+        // Can't buy once bidding has reached the buy now price.
+        require!(auction_state.buy_now_available(), AuctionError::BuyNowUnavailable);
+
+        // Can't buy on an Auction you're the authority of.
+        require!(
+            auction_state.authority.key() != ctx.accounts.buyer.key(),
+            AuctionError::AuctionAuthorityBid
+        );
+
+        // Can't buy without holding what the auction is gated on.
+        let payout_accounts = assert_bid_gate(&auction_state.bid_gate, ctx.accounts.buyer.key, ctx.remaining_accounts)?;
+
+        let price = auction_state.buy_now_price.unwrap();
+
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.buyer_token_account.to_account_info(),
+            destination_owner: ctx.accounts.buyer.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.buyer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        auction_state.state = settle_buy_now(
+            transfer_accounts,
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.fee_recipient.to_account_info(),
+            &ctx.accounts.config,
+            &auction_state.seller_splits,
+            payout_accounts,
+            auction_state.id,
+            auction_state.bump,
+            auction_state.state,
+            price
+        )?;
+
+        auction_state.current_bid = price;
+        auction_state.last_bidder = ctx.accounts.buyer.key.clone();
+        auction_state.claimed = 1;
+
+        Ok(())
+    }
+
+    pub fn buy_now_english<'info>(ctx: Context<'_, '_, '_, 'info, BuyNowEnglish<'info>>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't buy on an Auction that is not running.
+        assert_auction_active_english(&auction_state)?;
+
+        // Can't buy on an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+
+        // Can't buy once bidding has reached the buy now price.
+        require!(auction_state.buy_now_available(), AuctionError::BuyNowUnavailable);
+
+        // Can't buy on an Auction you're the authority of.
+        require!(
+            auction_state.authority.key() != ctx.accounts.buyer.key(),
+            AuctionError::AuctionAuthorityBid
+        );
+
+        // Can't buy without holding what the auction is gated on.
+        let payout_accounts = assert_bid_gate(&auction_state.bid_gate, ctx.accounts.buyer.key, ctx.remaining_accounts)?;
+
+        let price = auction_state.buy_now_price.unwrap();
+
+        /* Refund the outbid user, or their proxy escrow */
+        let previous_bidder = ctx.accounts.previous_bidder.as_ref().map(|previous_bidder| previous_bidder.to_account_info());
+        refund_english_leader(
+            &auction_state,
+            previous_bidder.as_ref(),
+            &ctx.accounts.leader_proxy.to_account_info()
+        )?;
+        auction_state.proxy_max = 0;
+
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.buyer_token_account.to_account_info(),
+            destination_owner: ctx.accounts.buyer.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.buyer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        auction_state.state = settle_buy_now(
+            transfer_accounts,
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.fee_recipient.to_account_info(),
+            &ctx.accounts.config,
+            &auction_state.seller_splits,
+            payout_accounts,
+            auction_state.id,
+            auction_state.bump,
+            auction_state.state,
+            price
+        )?;
+
+        auction_state.highest_bid = price;
+        auction_state.last_bidder = ctx.accounts.buyer.key.clone();
+        auction_state.claimed = 1;
+
+        Ok(())
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // let clock = Clock::get()?;
+
+        // Can't withdraw on an Auction that is ongoing.
+        // require!(auction_state.ended(clock.unix_timestamp)?,
+        //     AuctionError::AuctionOngoing);
+        assert_auction_over(&auction_state)?;
+
+        // Can't claim on an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        require!(auction_state.state == AuctionState::Ended, AuctionError::InvalidState);
+
+        // If last_bidder is system program Id => no bids has been placed => claimable only by authority
+        // Same goes for an Auction which did not meet its reserve price
+        let sold = auction_state.last_bidder.key() != system_program::ID.key()
+            && auction_state.reserve_met();
+
+        if !sold {
+            require!(
+                auction_state.authority.key() == ctx.accounts.winner.key(),
+                AuctionError::YouAreNotTheAuthority
+            );
+        } else {
+            require!(
+                auction_state.last_bidder.key() == ctx.accounts.winner.key(),
+                AuctionError::YouAreNotTheWinner
+            );
+        }
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let auction_state_clone = auction_state.to_account_info();
+
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.winner_token_account.to_account_info(),
+            destination_owner: ctx.accounts.winner.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.winner.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][claim_rewards] treasury transfered token.");
+
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        if !sold {
+            if let Some(reserve_price) = auction_state.reserve_price {
+                emit!(BoyncReserveNotMetEvent {
+                    auction_pubkey: auction_state.key(),
+                    final_bid_value: auction_state.highest_bid,
+                    reserve_price: reserve_price,
+                    label: "reserve".to_string(),
+                });
+            }
+        }
+
+        auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        emit!(BoyncClaimEvent {
+            auction_pubkey: auction_state.key(),
+            claimed: auction_state.claimed,
+            label: "claim".to_string(),
+        });
+
+        msg!("[BoyncDebug][claim_rewards] BoyncClaimEvent sent.");
+
+        close_settled_auction2(
+            auction_state,
+            &ctx.accounts.bidders_chest.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn claim_rewards3<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRewards3<'info>>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // let clock = Clock::get()?;
+
+        // Can't withdraw on an Auction that is not ended.
+        assert_auction_over_v3(&auction_state)?;
+
+        // Can't claim on an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        require!(auction_state.state == AuctionState::Ended, AuctionError::InvalidState);
+
+        // If last_bidder is system program Id => no bids has been placed => claimable only by authority
+        // Same goes for an Auction which did not meet its reserve price
+        let sold = auction_state.last_bidder.key() != system_program::ID.key()
+            && auction_state.reserve_met();
+
+        if !sold {
+            require!(
+                auction_state.authority.key() == ctx.accounts.winner.key(),
+                AuctionError::YouAreNotTheAuthority
+            );
+        } else {
+            require!(
+                auction_state.last_bidder.key() == ctx.accounts.winner.key(),
+                AuctionError::YouAreNotTheWinner
+            );
+
+            // Can't pay once the payment window is over.
+            require!(
+                current_timestamp_ms()? <= auction_state.payment_deadline()?,
+                AuctionError::PaymentWindowExpired
+            );
+        }
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        /* We first ask user to transfer the final auction price, aka `current_bid`, less creator royalties and the protocol fee */
+        if sold {
+            let royalties = pay_creator_royalties(
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.winner.to_account_info(),
+                &ctx.accounts.metadata.to_account_info(),
+                &ctx.accounts.treasury_mint.key(),
+                ctx.remaining_accounts,
+                auction_state.current_bid,
+                &[]
+            )?;
+
+            pay_with_protocol_fee(
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.winner.to_account_info(),
+                &auction_state.to_account_info(),
+                &auction_state.seller_splits,
+                ctx.remaining_accounts,
+                &ctx.accounts.fee_recipient.to_account_info(),
+                &ctx.accounts.config,
+                pricing::checked_sub(auction_state.current_bid, royalties)?,
+                &[]
+            )?;
+        }
+
+        /* Finally we transfer the NFT to the winner */
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.winner_token_account.to_account_info(),
+            destination_owner: ctx.accounts.winner.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.winner.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][claim_rewards] treasury transfered token.");
+
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        if !sold {
+            if let Some(reserve_price) = auction_state.reserve_price {
+                emit!(BoyncReserveNotMetEvent {
+                    auction_pubkey: auction_state.key(),
+                    final_bid_value: auction_state.current_bid,
+                    reserve_price: reserve_price,
+                    label: "reserve".to_string(),
+                });
+            }
+        }
+
+        auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        emit!(BoyncClaimEvent {
+            auction_pubkey: auction_state.key(),
+            claimed: auction_state.claimed,
+            label: "claim".to_string(),
+        });
+
+        msg!("[BoyncDebug][claim_rewards] BoyncClaimEvent sent.");
+
+        // State is only needed for payouts and refunds, none are left without bids.
+        if auction_state.last_bidder.key() == system_program::ID.key() {
+            auction_state.close(ctx.accounts.authority.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn settle<'info>(ctx: Context<'_, '_, '_, 'info, Settle<'info>>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't settle an Auction that is ongoing.
+        assert_auction_over(&auction_state)?;
+
+        // Can't settle an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        require!(auction_state.state == AuctionState::Ended, AuctionError::InvalidState);
+
+        let sold = auction_state.last_bidder.key() != system_program::ID.key()
+            && auction_state.reserve_met();
+
+        // Token goes to the winner, or back to the authority when the auction wasn't sold.
+        let recipient = if sold { auction_state.last_bidder } else { auction_state.authority };
+        require!(ctx.accounts.recipient.key() == recipient, AuctionError::PublicKeyMismatch);
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.recipient_token_account.to_account_info(),
+            destination_owner: ctx.accounts.recipient.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.keeper.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][settle] treasury transfered token.");
+
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        let mut proceeds: u64 = 0;
+        let mut crank_fee: u64 = 0;
+        let mut protocol_fee: u64 = 0;
+        let mut royalties: u64 = 0;
+
+        if sold {
+            // Proceeds may already have been paid out through `end`.
+            if !auction_state.proceeds_paid() {
+                /* Route the bidders_chest to the authority, minus creator royalties, the keeper's crank fee and the protocol fee */
+                let bump = *ctx.bumps.get("bidders_chest").unwrap();
+                let chest_seeds = &[
+                    CHEST_SEED,
+                    auction_auth.as_ref(),
+                    app_idx_bytes.as_ref(),
+                    &[bump],
+                ];
+                let chest_signer_seeds = &[&chest_seeds[..]];
+
+                let total_lamports: u64 = ctx.accounts.bidders_chest.lamports();
+                let seller_total: u64;
+                (crank_fee, seller_total) = pricing::split_bps(total_lamports, auction_state.crank_fee_bps)?;
+
+                royalties = pay_creator_royalties(
+                    &ctx.accounts.system_program.to_account_info(),
+                    &ctx.accounts.bidders_chest.to_account_info(),
+                    &ctx.accounts.metadata.to_account_info(),
+                    &ctx.accounts.treasury_mint.key(),
+                    ctx.remaining_accounts,
+                    total_lamports,
+                    chest_signer_seeds
+                )?;
+
+                (proceeds, protocol_fee) = pay_with_protocol_fee(
+                    &ctx.accounts.system_program.to_account_info(),
+                    &ctx.accounts.bidders_chest.to_account_info(),
+                    &ctx.accounts.authority.to_account_info(),
+                    &auction_state.seller_splits,
+                    ctx.remaining_accounts,
+                    &ctx.accounts.fee_recipient.to_account_info(),
+                    &ctx.accounts.config,
+                    pricing::checked_sub(seller_total, royalties)?,
+                    chest_signer_seeds
+                )?;
+
+                if crank_fee > 0 {
+                    system_program::transfer(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.system_program.to_account_info(),
+                            system_program::Transfer {
+                                from: ctx.accounts.bidders_chest.to_account_info(),
+                                to: ctx.accounts.keeper.to_account_info(),
+                            },
+                            chest_signer_seeds
+                        ),
+                        crank_fee
+                    )?;
+                }
+
+                auction_state.proceeds = Some(ProceedsPayout {
+                    seller: proceeds,
+                    protocol_fee,
+                    royalties,
+                    crank_fee,
+                });
+
+                emit!(BoyncProceedsEvent {
+                    auction_pubkey: auction_state.key(),
+                    seller_pubkey: auction_state.authority.clone(),
+                    seller_amount: proceeds,
+                    fee_amount: protocol_fee,
+                    royalties,
+                    label: "proceeds".to_string(),
+                });
+            }
+        } else if let Some(reserve_price) = auction_state.reserve_price {
+            // Bids are refunded through `refund_bid`.
+            emit!(BoyncReserveNotMetEvent {
+                auction_pubkey: auction_state.key(),
+                final_bid_value: auction_state.highest_bid,
+                reserve_price: reserve_price,
+                label: "reserve".to_string(),
+            });
+        }
+
+        auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        emit!(BoyncSettleEvent {
+            auction_pubkey: auction_state.key(),
+            recipient_pubkey: recipient,
+            keeper_pubkey: ctx.accounts.keeper.key(),
+            proceeds,
+            crank_fee,
+            protocol_fee,
+            royalties,
+            label: "settle".to_string(),
+        });
+
+        // Bids are refunded from the bidders_chest when the reserve wasn't met, the state is kept until the last `refund_bid`.
+        close_settled_auction2(
+            auction_state,
+            &ctx.accounts.bidders_chest.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn settle3(ctx: Context<Settle3>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't settle an Auction that is not ended.
+        assert_auction_over_v3(&auction_state)?;
+
+        // Can't settle an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        require!(auction_state.state == AuctionState::Ended, AuctionError::InvalidState);
+
+        let sold = auction_state.last_bidder.key() != system_program::ID.key()
+            && auction_state.reserve_met();
+
+        // Can't settle for a winner, they pay when claiming.
+        require!(!sold, AuctionError::WinnerMustClaim);
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.authority_token_account.to_account_info(),
+            destination_owner: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.keeper.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][settle3] treasury transfered token.");
+
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        if let Some(reserve_price) = auction_state.reserve_price {
+            emit!(BoyncReserveNotMetEvent {
+                auction_pubkey: auction_state.key(),
+                final_bid_value: auction_state.current_bid,
+                reserve_price: reserve_price,
+                label: "reserve".to_string(),
+            });
+        }
+
+        auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        emit!(BoyncSettleEvent {
+            auction_pubkey: auction_state.key(),
+            recipient_pubkey: auction_state.authority.clone(),
+            keeper_pubkey: ctx.accounts.keeper.key(),
+            proceeds: 0,
+            crank_fee: 0,
+            protocol_fee: 0,
+            royalties: 0,
+            label: "settle".to_string(),
+        });
+
+        // State is only needed for payouts and refunds, none are left without bids.
+        if auction_state.last_bidder.key() == system_program::ID.key() {
+            auction_state.close(ctx.accounts.authority.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn reclaim_defaulted3(ctx: Context<ReclaimDefaulted3>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        assert_auction_over_v3(&auction_state)?;
+        assert_payment_defaulted_v3(&auction_state)?;
+
+        let defaulter = auction_state.last_bidder.clone();
+        flag_defaulter(&mut ctx.accounts.defaulter, defaulter, auction_state.key(), auction_state.current_bid)?;
+
+        // Defaulter's deposit goes to the authority by closing `defaulter_deposit`.
+        let slashed_deposit = slash_defaulter_deposit(
+            auction_state,
+            &ctx.accounts.defaulter_deposit.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.authority_token_account.to_account_info(),
+            destination_owner: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.authority.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds
+        );
+
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
+
+        msg!("[BoyncDebug][reclaim_defaulted3] treasury transfered token.");
+
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        emit!(BoyncDefaultEvent {
+            auction_pubkey: auction_state.key(),
+            defaulter_pubkey: defaulter,
+            unpaid_bid: auction_state.current_bid,
+            offered_to: None,
+            slashed_deposit,
+            label: "default".to_string(),
+        });
+
+        auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        Ok(())
+    }
+
+    pub fn offer_runner_up3(ctx: Context<OfferRunnerUp3>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        assert_auction_over_v3(&auction_state)?;
+        assert_payment_defaulted_v3(&auction_state)?;
+
+        // Can't offer the token to nobody.
+        require!(auction_state.has_runner_up(), AuctionError::NoRunnerUp);
+
+        // Can't sell to the runner-up below the reserve price.
+        if let Some(reserve_price) = auction_state.reserve_price {
+            require!(auction_state.runner_up_bid >= reserve_price, AuctionError::ReserveNotMet);
+        }
+
+        let defaulter = auction_state.last_bidder.clone();
+        let unpaid_bid = auction_state.current_bid;
+        flag_defaulter(&mut ctx.accounts.defaulter, defaulter, auction_state.key(), unpaid_bid)?;
+
+        // Defaulter's deposit goes to the authority by closing `defaulter_deposit`.
+        let slashed_deposit = slash_defaulter_deposit(
+            auction_state,
+            &ctx.accounts.defaulter_deposit.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        /* Runner-up becomes the winner at their last price, with a fresh payment window */
+        auction_state.last_bidder = auction_state.runner_up.clone();
+        auction_state.current_bid = auction_state.runner_up_bid;
+        auction_state.runner_up = system_program::ID;
+        auction_state.runner_up_bid = 0;
+        auction_state.payment_due_at = current_timestamp_ms()?
+            .checked_add(auction_state.payment_window)
+            .ok_or(AuctionError::NumericalOverflow)?;
+
+        emit!(BoyncDefaultEvent {
+            auction_pubkey: auction_state.key(),
+            defaulter_pubkey: defaulter,
+            unpaid_bid,
+            offered_to: Some(auction_state.last_bidder.clone()),
+            slashed_deposit,
+            label: "default".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn refund_deposit3(ctx: Context<RefundDeposit3>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        // Can't refund before the winner paid or the auction got settled without them.
+        require!(auction_state.claimed == 1, AuctionError::DepositLocked);
+
+        auction_state.open_deposits = pricing::checked_sub(auction_state.open_deposits, 1)?;
+
+        // Deposit and the rent are returned by closing `deposit`.
+        emit!(BoyncRefundEvent {
+            auction_pubkey: auction_state.key(),
+            bidder_pubkey: ctx.accounts.bidder.key(),
+            refunded_value: ctx.accounts.deposit.amount,
+            label: "refund_deposit".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_proceeds3(ctx: Context<WithdrawProceeds3>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't withdraw on an Auction that is ongoing.
+        require!(auction_state.state.is_over(), AuctionError::InvalidState);
+
+        let state_info = auction_state.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(state_info.data_len());
+        let amount = state_info.lamports().saturating_sub(rent_exempt);
+
+        // Once settled, the chest withdrawn and the deposits refunded, nothing is left on the state.
+        let closable = auction_state.state == AuctionState::Settled
+            && ctx.accounts.chest.data_is_empty()
+            && auction_state.open_deposits == 0;
+
+        // Can't withdraw when only the rent is left.
+        require!(amount > 0 || closable, AuctionError::NothingToWithdraw);
+
+        if amount > 0 {
+            transfer_lamports_from_pda(
+                &state_info,
+                &ctx.accounts.authority.to_account_info(),
+                amount
+            )?;
+        }
+
+        emit!(BoyncWithdrawEvent {
+            auction_pubkey: auction_state.key(),
+            authority_pubkey: ctx.accounts.authority.key(),
+            mint: system_program::ID,
+            amount,
+            label: "withdraw_proceeds".to_string(),
+        });
+
+        if closable {
+            auction_state.close(ctx.accounts.authority.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn withdraw_chest3(ctx: Context<WithdrawChest3>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't withdraw while bids still add tokens to the chest.
+        require!(auction_state.state.is_over(), AuctionError::InvalidState);
+
+        let amount = ctx.accounts.chest.amount;
+
+        // Can't withdraw an empty chest.
+        require!(amount > 0, AuctionError::NothingToWithdraw);
+
+        let treasury_mint = auction_state.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from:       ctx.accounts.chest.to_account_info(),
+                    to:         ctx.accounts.authority_token_account.to_account_info(),
+                    authority:  auction_state.to_account_info(),
+                },
+                signer_seeds
+            ),
+            amount
+        )?;
+
+        /* No more bids can reach the chest, close it back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.chest.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        emit!(BoyncWithdrawEvent {
+            auction_pubkey: auction_state.key(),
+            authority_pubkey: ctx.accounts.authority.key(),
+            mint: ctx.accounts.chest.mint,
+            amount,
+            label: "withdraw_chest".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_rewards_english<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRewardsEnglish<'info>>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't withdraw on an Auction that is not ended.
+        assert_auction_over_english(&auction_state)?;
 
-        // Just transfer SPL Token to bidders_chest
-        // let _bump = *ctx.bumps.get("auction").unwrap();
-        // let bump = auction_state.bump;
+        // Can't claim on an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        require!(auction_state.state == AuctionState::Ended, AuctionError::InvalidState);
+
+        // If last_bidder is system program Id => no bids has been placed => claimable only by authority
+        if !auction_state.has_bids() {
+            require!(
+                auction_state.authority.key() == ctx.accounts.winner.key(),
+                AuctionError::YouAreNotTheAuthority
+            );
+        } else {
+            require!(
+                auction_state.last_bidder.key() == ctx.accounts.winner.key(),
+                AuctionError::YouAreNotTheWinner
+            );
+        }
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
         let auction_auth = auction_state.authority.clone();
-        let treasury_mint = auction_state.treasury_mint.key().clone();
         let app_idx_bytes = auction_state.id.to_le_bytes();
         let seeds = &[
             AUCTION_SEED,
@@ -507,110 +2809,193 @@ pub mod boync_anchor_program {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        // Token program instruction to send SPL token.
-        let transfer_instruction = Transfer {
-            from:       ctx.accounts.bidder_token_account.to_account_info(),
-            to:         ctx.accounts.chest.to_account_info(),
-            authority:  ctx.accounts.bidder.to_account_info(),
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.winner_token_account.to_account_info(),
+            destination_owner: ctx.accounts.winner.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.winner.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
         };
 
         let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            transfer_instruction,
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
             signer_seeds
         );
 
-        anchor_spl::token::transfer(cpi_ctx, 1)?;
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
 
-        /* Increase current_bid by one hundreth of a SOL */
-        auction_state.current_bid += LAMPORTS_PER_SOL / 100;
-        auction_state.last_bidder = ctx.accounts.bidder.key.clone();
-        process_time_extension_v3(auction_state)?;
+        msg!("[BoyncDebug][claim_rewards_english] treasury transfered token.");
 
-        /* Store bid state */
-        let bidder_state = &mut ctx.accounts.bidder_state;
-        bidder_state.auction = auction_state.key();
-        bidder_state.bidder = ctx.accounts.bidder.key.clone();
-        bidder_state.bid_value = auction_state.current_bid;
-        bidder_state.ts = ts;
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
 
-        emit!(BoyncBidEvent {
+        /* Release the escrowed highest bid to the authority, less creator royalties and the protocol fee */
+        if auction_state.has_bids() {
+            let royalties = pay_creator_royalties_from_pda(
+                &auction_state.to_account_info(),
+                &ctx.accounts.metadata.to_account_info(),
+                &ctx.accounts.treasury_mint.key(),
+                ctx.remaining_accounts,
+                auction_state.highest_bid
+            )?;
+
+            pay_with_protocol_fee_from_pda(
+                &auction_state.to_account_info(),
+                &ctx.accounts.authority.to_account_info(),
+                &auction_state.seller_splits,
+                ctx.remaining_accounts,
+                &ctx.accounts.fee_recipient.to_account_info(),
+                &ctx.accounts.config,
+                pricing::checked_sub(auction_state.highest_bid, royalties)?
+            )?;
+        }
+
+        auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        emit!(BoyncClaimEvent {
             auction_pubkey: auction_state.key(),
-            bidder_pubkey: auction_state.last_bidder.clone(),
-            updated_bid_value: auction_state.current_bid.clone(),
-            updated_end_timestamp: auction_state.end_auction_at,
-            label: "bid".to_string(),
-            ts: ts,
+            claimed: auction_state.claimed,
+            label: "claim".to_string(),
         });
 
+        /* Highest bid was paid out, nothing is left on the state */
+        auction_state.close(ctx.accounts.authority.to_account_info())?;
+
         Ok(())
     }
 
-    /// Bid
-    /* 
-    pub fn bid(ctx: Context<UpdateAuction>, amount: u64) -> Result<()> {
+    pub fn claim_rewards_dutch(ctx: Context<ClaimRewardsDutch>) -> Result<()> {
         let auction_state = &mut ctx.accounts.state;
-        let clock = Clock::get()?;
 
-        // Can't bid on an Auction that is expired.
-        require!(!auction_state.ended(clock.unix_timestamp)?,
-            AuctionError::AuctionExpired);
-    
-        // Can't bid on an Auction that is not started.
-        require!(auction_state.state == AuctionState::Started,
-            AuctionError::InvalidState);
+        sync_scheduled_auction(auction_state)?;
 
-        // Just transfer SPL Token to bidders_chest
-        // let _bump = *ctx.bumps.get("auction").unwrap();
-        // let bump = auction_state.bump;
+        // Can't withdraw on an Auction that is not ended.
+        assert_auction_over_dutch(&auction_state)?;
+
+        // Can't claim on an Auction that was already sold or claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        require!(auction_state.state == AuctionState::Ended, AuctionError::InvalidState);
+
+        // Nobody accepted the price => claimable only by authority
+        require!(
+            auction_state.authority.key() == ctx.accounts.winner.key(),
+            AuctionError::YouAreNotTheAuthority
+        );
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
         let auction_auth = auction_state.authority.clone();
-        let treasury_mint = auction_state.treasury_mint.key().clone();
         let app_idx_bytes = auction_state.id.to_le_bytes();
         let seeds = &[
             AUCTION_SEED,
             auction_auth.as_ref(),
             treasury_mint.as_ref(),
             app_idx_bytes.as_ref(),
-            &[auction_state.bump]
+            &[auction_state.bump],
         ];
         let signer_seeds = &[&seeds[..]];
 
-        // Token program instruction to send SPL token.
-        let transfer_instruction = Transfer {
-            from:       ctx.accounts.signer_ata.to_account_info(),
-            to:         ctx.accounts.bidders_chest.to_account_info(),
-            authority:  ctx.accounts.bidder.to_account_info(),
+        let auction_state_clone = auction_state.to_account_info();
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.winner_token_account.to_account_info(),
+            destination_owner: ctx.accounts.winner.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.winner.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
         };
 
         let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            transfer_instruction,
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
             signer_seeds
         );
 
-        anchor_spl::token::transfer(cpi_ctx, amount)?;
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
 
-        auction_state.last_bidder = ctx.accounts.bidder.key.clone();
-        auction_state.end_auction_at += 60 * MS_IN_SEC; // Add 60 seconds to countdown
-        auction_state.tokens_spent += 1;
+        msg!("[BoyncDebug][claim_rewards_dutch] treasury transfered token.");
+
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
+
+        emit!(BoyncClaimEvent {
+            auction_pubkey: auction_state.key(),
+            claimed: auction_state.claimed,
+            label: "claim".to_string(),
+        });
+
+        /* Token is back with the authority, nothing is left on the state */
+        auction_state.close(ctx.accounts.authority.to_account_info())?;
 
         Ok(())
     }
-    */
-    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+
+    pub fn claim_rewards_sealed<'info>(ctx: Context<'_, '_, '_, 'info, ClaimRewardsSealed<'info>>) -> Result<()> {
         let auction_state = &mut ctx.accounts.state;
-        // let clock = Clock::get()?;
 
-        // Can't withdraw on an Auction that is ongoing.
-        // require!(auction_state.ended(clock.unix_timestamp)?,
-        //     AuctionError::AuctionOngoing);
-        assert_auction_over(&auction_state)?;
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't withdraw on an Auction before the reveal window is over.
+        assert_auction_over_sealed(&auction_state)?;
 
         // Can't claim on an Auction that was already claimed.
         require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        require!(auction_state.state == AuctionState::Ended, AuctionError::InvalidState);
 
-        // If last_bidder is system program Id => no bids has been placed => claimable only by authority
-        if auction_state.last_bidder.key() == system_program::ID.key() {
+        // If last_bidder is system program Id => no bids has been revealed => claimable only by authority
+        if !auction_state.has_bids() {
             require!(
                 auction_state.authority.key() == ctx.accounts.winner.key(),
                 AuctionError::YouAreNotTheAuthority
@@ -620,6 +3005,31 @@ pub mod boync_anchor_program {
                 auction_state.last_bidder.key() == ctx.accounts.winner.key(),
                 AuctionError::YouAreNotTheWinner
             );
+
+            /* Winner pays the clearing price out of the escrowed deposit */
+            let winner_bid = match &ctx.accounts.winner_bid {
+                Some(winner_bid) => winner_bid,
+                None => return err!(AuctionError::BidRecordMissing),
+            };
+
+            let clearing_price = auction_state.clearing_price();
+            let royalties = pay_creator_royalties_from_pda(
+                &winner_bid.to_account_info(),
+                &ctx.accounts.metadata.to_account_info(),
+                &ctx.accounts.treasury_mint.key(),
+                ctx.remaining_accounts,
+                clearing_price
+            )?;
+
+            pay_with_protocol_fee_from_pda(
+                &winner_bid.to_account_info(),
+                &ctx.accounts.authority.to_account_info(),
+                &auction_state.seller_splits,
+                ctx.remaining_accounts,
+                &ctx.accounts.fee_recipient.to_account_info(),
+                &ctx.accounts.config,
+                pricing::checked_sub(clearing_price, royalties)?
+            )?;
         }
 
         let treasury_mint = ctx.accounts.treasury_mint.key().clone();
@@ -635,7 +3045,6 @@ pub mod boync_anchor_program {
         let signer_seeds = &[&seeds[..]];
 
         let auction_state_clone = auction_state.to_account_info();
-
         let transfer_accounts = BoyncTokenTransfer {
             auction_state: auction_state_clone.to_account_info(),
             token: ctx.accounts.treasury.to_account_info(),
@@ -665,13 +3074,24 @@ pub mod boync_anchor_program {
 
         token_transfer(cpi_ctx, &auction_state.id, 1)?;
 
-        msg!("[BoyncDebug][claim_rewards] treasury transfered token.");
+        msg!("[BoyncDebug][claim_rewards_sealed] treasury transfered token.");
 
-        // Use the `reload()` function on an account to reload it's state. Since we performed the
-        // transfer, we are expecting the `amount` field to have changed.
-        // TODO: *PROPERLY CLOSE TREASURY ACCOUNT*
+        /* Close the now empty treasury back to the authority */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
 
         auction_state.claimed = 1;
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::settle,
+            "settle"
+        )?;
 
         emit!(BoyncClaimEvent {
             auction_pubkey: auction_state.key(),
@@ -679,33 +3099,22 @@ pub mod boync_anchor_program {
             label: "claim".to_string(),
         });
 
-        msg!("[BoyncDebug][claim_rewards] BoyncClaimEvent sent.");
-
         Ok(())
     }
 
-    pub fn claim_rewards3(ctx: Context<ClaimRewards3>) -> Result<()> {
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
         let auction_state = &mut ctx.accounts.state;
-        // let clock = Clock::get()?;
 
-        // Can't withdraw on an Auction that is not ended.
-        assert_auction_over_v3(&auction_state)?;
+        sync_scheduled_auction(auction_state)?;
 
-        // Can't claim on an Auction that was already claimed.
-        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+        // Can't cancel an Auction once somebody bid on it.
+        require!(
+            auction_state.last_bidder.key() == system_program::ID.key(),
+            AuctionError::AuctionHasBids
+        );
 
-        // If last_bidder is system program Id => no bids has been placed => claimable only by authority
-        if auction_state.last_bidder.key() == system_program::ID.key() {
-            require!(
-                auction_state.authority.key() == ctx.accounts.winner.key(),
-                AuctionError::YouAreNotTheAuthority
-            );
-        } else {
-            require!(
-                auction_state.last_bidder.key() == ctx.accounts.winner.key(),
-                AuctionError::YouAreNotTheWinner
-            );
-        }
+        // Can't cancel an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
 
         let treasury_mint = ctx.accounts.treasury_mint.key().clone();
         let auction_auth = auction_state.authority.clone();
@@ -719,36 +3128,105 @@ pub mod boync_anchor_program {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        /* We first ask user to transfer the final auction price, aka `current_bid` */
+        /* Return the NFT to the authority */
         let auction_state_clone = auction_state.to_account_info();
-        let transfer_instruction = anchor_lang::system_program::Transfer {
-            from: ctx.accounts.winner.to_account_info(),
-            to: auction_state_clone,
+        let transfer_accounts = BoyncTokenTransfer {
+            auction_state: auction_state_clone.to_account_info(),
+            token: ctx.accounts.treasury.to_account_info(),
+            token_owner: auction_state_clone.to_account_info(),
+            destination: ctx.accounts.authority_token_account.to_account_info(),
+            destination_owner: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.treasury_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            edition: ctx.accounts.edition.to_account_info(),
+            owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
+            destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
+            authority: auction_state_clone.to_account_info(),
+            payer: ctx.accounts.authority.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            spl_token_program: ctx.accounts.token_program.to_account_info(),
+            spl_ata_program: ctx.accounts.associated_token_program.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_token_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
         };
 
         let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            transfer_instruction,
+            ctx.accounts.token_metadata_program.to_account_info(),
+            transfer_accounts,
             signer_seeds
         );
 
-        anchor_lang::system_program::transfer(cpi_ctx, auction_state.current_bid.clone())?;
+        token_transfer(cpi_ctx, &auction_state.id, 1)?;
 
-        /* Finally we transfer the NFT to the winner */
+        msg!("[BoyncDebug][cancel_auction] treasury transfered token.");
+
+        /* Close the now empty treasury, state is closed by anchor */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::cancel,
+            "cancel"
+        )?;
+
+        emit!(BoyncCancelEvent {
+            auction_pubkey: auction_state.key(),
+            authority_pubkey: auction_state.authority.clone(),
+            label: "cancel".to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_auction3(ctx: Context<CancelAuction3>) -> Result<()> {
+        let auction_state = &mut ctx.accounts.state;
+
+        sync_scheduled_auction(auction_state)?;
+
+        // Can't cancel an Auction once somebody bid on it.
+        require!(
+            auction_state.last_bidder.key() == system_program::ID.key(),
+            AuctionError::AuctionHasBids
+        );
+
+        // Can't cancel an Auction that was already claimed.
+        require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+
+        let treasury_mint = ctx.accounts.treasury_mint.key().clone();
+        let auction_auth = auction_state.authority.clone();
+        let app_idx_bytes = auction_state.id.to_le_bytes();
+        let seeds = &[
+            AUCTION_SEED,
+            auction_auth.as_ref(),
+            treasury_mint.as_ref(),
+            app_idx_bytes.as_ref(),
+            &[auction_state.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        /* Return the NFT to the authority */
         let auction_state_clone = auction_state.to_account_info();
         let transfer_accounts = BoyncTokenTransfer {
             auction_state: auction_state_clone.to_account_info(),
             token: ctx.accounts.treasury.to_account_info(),
             token_owner: auction_state_clone.to_account_info(),
-            destination: ctx.accounts.winner_token_account.to_account_info(),
-            destination_owner: ctx.accounts.winner.to_account_info(),
+            destination: ctx.accounts.authority_token_account.to_account_info(),
+            destination_owner: ctx.accounts.authority.to_account_info(),
             mint: ctx.accounts.treasury_mint.to_account_info(),
             metadata: ctx.accounts.metadata.to_account_info(),
             edition: ctx.accounts.edition.to_account_info(),
             owner_token_record: ctx.accounts.owner_token_record.to_account_info(),
             destination_token_record: ctx.accounts.destination_token_record.to_account_info(),
             authority: auction_state_clone.to_account_info(),
-            payer: ctx.accounts.winner.to_account_info(),
+            payer: ctx.accounts.authority.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
             sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
             spl_token_program: ctx.accounts.token_program.to_account_info(),
@@ -765,22 +3243,60 @@ pub mod boync_anchor_program {
 
         token_transfer(cpi_ctx, &auction_state.id, 1)?;
 
-        msg!("[BoyncDebug][claim_rewards] treasury transfered token.");
+        msg!("[BoyncDebug][cancel_auction3] treasury transfered token.");
 
-        // Use the `reload()` function on an account to reload it's state. Since we performed the
-        // transfer, we are expecting the `amount` field to have changed.
-        // TODO: *PROPERLY CLOSE TREASURY ACCOUNT*
+        /* Close the now empty treasury, state is closed by anchor */
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
 
-        auction_state.claimed = 1;
+        /* Chest is empty before the first bid, unless bid tokens were sent straight to it */
+        let chest_amount = ctx.accounts.chest.amount;
+        if chest_amount > 0 {
+            let authority_chest_token_account = match &ctx.accounts.authority_chest_token_account {
+                Some(authority_chest_token_account) => authority_chest_token_account,
+                None => return err!(AuctionError::ChestTokenAccountMissing),
+            };
+
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from:       ctx.accounts.chest.to_account_info(),
+                        to:         authority_chest_token_account.to_account_info(),
+                        authority:  auction_state.to_account_info(),
+                    },
+                    signer_seeds
+                ),
+                chest_amount
+            )?;
+        }
 
-        emit!(BoyncClaimEvent {
+        close_token_account(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.chest.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &auction_state.to_account_info(),
+            signer_seeds
+        )?;
+
+        auction_state.state = transition_auction_state(
+            auction_state.key(),
+            auction_state.state,
+            AuctionState::cancel,
+            "cancel"
+        )?;
+
+        emit!(BoyncCancelEvent {
             auction_pubkey: auction_state.key(),
-            claimed: auction_state.claimed,
-            label: "claim".to_string(),
+            authority_pubkey: auction_state.authority.clone(),
+            label: "cancel".to_string(),
         });
 
-        msg!("[BoyncDebug][claim_rewards] BoyncClaimEvent sent.");
-
         Ok(())
     }
 }
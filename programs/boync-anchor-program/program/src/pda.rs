@@ -2,6 +2,10 @@ use super::constants::*;
 use crate::id;
 use anchor_lang::prelude::Pubkey;
 
+pub fn find_boync_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_PREFIX.as_bytes()], &id())
+}
+
 pub fn find_boync_auction_address(
     authority: &Pubkey,
     mint_address: &Pubkey,
@@ -67,3 +71,56 @@ pub fn find_boync_bidder_state_address(
     ];
     Pubkey::find_program_address(seeds, &id())
 }
+
+pub fn find_boync_bid_counter_address(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[
+        BID_COUNTER_PREFIX.as_bytes(),
+        auction.as_ref(),
+        bidder.as_ref(),
+    ];
+    Pubkey::find_program_address(seeds, &id())
+}
+
+pub fn find_boync_bid_record_address(auction: &Pubkey, bidder: &Pubkey, seq: &u64) -> (Pubkey, u8) {
+    let seq_bytes = seq.to_le_bytes();
+    let seeds = &[
+        BIDDER_PREFIX.as_bytes(),
+        auction.as_ref(),
+        bidder.as_ref(),
+        seq_bytes.as_ref(),
+    ];
+    Pubkey::find_program_address(seeds, &id())
+}
+
+pub fn find_boync_sealed_bid_address(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[
+        BIDDER_PREFIX.as_bytes(),
+        auction.as_ref(),
+        bidder.as_ref(),
+    ];
+    Pubkey::find_program_address(seeds, &id())
+}
+
+pub fn find_boync_bid_deposit_address(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[
+        BIDDER_PREFIX.as_bytes(),
+        DEPOSIT_PREFIX.as_bytes(),
+        auction.as_ref(),
+        bidder.as_ref(),
+    ];
+    Pubkey::find_program_address(seeds, &id())
+}
+
+pub fn find_boync_proxy_bid_address(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[
+        BIDDER_PREFIX.as_bytes(),
+        PROXY_PREFIX.as_bytes(),
+        auction.as_ref(),
+        bidder.as_ref(),
+    ];
+    Pubkey::find_program_address(seeds, &id())
+}
+
+pub fn find_boync_defaulter_address(bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DEFAULTER_PREFIX.as_bytes(), bidder.as_ref()], &id())
+}
@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::AuctionError;
+
+/*
+ * Boync Pricing
+ *
+ * Checked integer math for every lamport amount the program computes.
+ * Percentages are expressed in basis points (1 bps = 0.01%), results round down.
+ */
+
+pub fn checked_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or_else(|| AuctionError::NumericalOverflow.into())
+}
+
+pub fn checked_sub(a: u64, b: u64) -> Result<u64> {
+    a.checked_sub(b).ok_or_else(|| AuctionError::NumericalOverflow.into())
+}
+
+/// `amount * bps / 10000`, rounded down.
+pub fn apply_bps(amount: u64, bps: u16) -> Result<u64> {
+    if bps > MAX_BASIS_POINTS {
+        return err!(AuctionError::InvalidBasisPoints);
+    }
+
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .and_then(|v| v.checked_div(MAX_BASIS_POINTS as u128))
+        .ok_or(AuctionError::NumericalOverflow)?;
+
+    u64::try_from(share).map_err(|_| AuctionError::NumericalOverflow.into())
+}
+
+/// `amount` increased by `bps`, e.g. 500 bps turns 1 SOL into 1.05 SOL.
+pub fn increase_by_bps(amount: u64, bps: u16) -> Result<u64> {
+    checked_add(amount, apply_bps(amount, bps)?)
+}
+
+/// Amount `increase_by_bps` grew into `amount`, e.g. 500 bps turns 1.05 SOL back into 1 SOL.
+/// Rounds up, undoing the rounded down increase exactly.
+pub fn reverse_increase_by_bps(amount: u64, bps: u16) -> Result<u64> {
+    let divisor = MAX_BASIS_POINTS as u128 + bps as u128;
+    let base = (amount as u128)
+        .checked_mul(MAX_BASIS_POINTS as u128)
+        .and_then(|v| v.checked_add(divisor - 1))
+        .and_then(|v| v.checked_div(divisor))
+        .ok_or(AuctionError::NumericalOverflow)?;
+
+    u64::try_from(base).map_err(|_| AuctionError::NumericalOverflow.into())
+}
+
+/// Splits `amount` into (`bps` share, remainder).
+/// Rounding dust stays in the remainder, so both parts always add up to `amount`.
+pub fn split_bps(amount: u64, bps: u16) -> Result<(u64, u64)> {
+    let share = apply_bps(amount, bps)?;
+
+    Ok((share, checked_sub(amount, share)?))
+}
+
+/// Price moving linearly from `start_price` to `end_price` over `duration`, rounded towards `start_price`.
+pub fn interpolate_price(start_price: u64, end_price: u64, elapsed: u64, duration: u64) -> Result<u64> {
+    if duration == 0 || elapsed >= duration {
+        return Ok(end_price);
+    }
+
+    let delta = start_price.abs_diff(end_price);
+
+    let step = (delta as u128)
+        .checked_mul(elapsed as u128)
+        .and_then(|v| v.checked_div(duration as u128))
+        .ok_or(AuctionError::NumericalOverflow)? as u64;
+
+    if start_price >= end_price {
+        checked_sub(start_price, step)
+    } else {
+        checked_add(start_price, step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod pricing_properties {
+
+        use super::*;
+
+        const ITERATIONS: usize = 10_000;
+
+        /* Small deterministic xorshift generator so failures are reproducible */
+        struct Rng(u64);
+
+        impl Rng {
+            fn next(&mut self) -> u64 {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                self.0
+            }
+
+            fn bps(&mut self) -> u16 {
+                (self.next() % (MAX_BASIS_POINTS as u64 + 1)) as u16
+            }
+        }
+
+        fn edge_amounts() -> Vec<u64> {
+            vec![0, 1, 2, 3, 9_999, 10_000, 10_001, 1_000_000_000, u64::MAX / 10_000, u64::MAX - 1, u64::MAX]
+        }
+
+        fn edge_bps() -> Vec<u16> {
+            vec![0, 1, 500, 2_500, 7_500, 9_999, MAX_BASIS_POINTS]
+        }
+
+        #[test]
+        fn split_never_creates_or_loses_lamports() {
+            let mut rng = Rng(0x5eed_b07c);
+
+            for amount in edge_amounts() {
+                for bps in edge_bps() {
+                    let (share, remainder) = split_bps(amount, bps).unwrap();
+                    assert_eq!(share as u128 + remainder as u128, amount as u128);
+                }
+            }
+
+            for _ in 0..ITERATIONS {
+                let amount = rng.next();
+                let bps = rng.bps();
+
+                let (share, remainder) = split_bps(amount, bps).unwrap();
+                assert_eq!(share as u128 + remainder as u128, amount as u128);
+                assert!(share <= amount);
+            }
+        }
+
+        #[test]
+        fn apply_bps_rounds_down_and_is_bounded() {
+            let mut rng = Rng(0xdead_beef);
+
+            for _ in 0..ITERATIONS {
+                let amount = rng.next();
+                let bps = rng.bps();
+
+                let share = apply_bps(amount, bps).unwrap() as u128;
+                let exact = amount as u128 * bps as u128;
+
+                assert!(share * MAX_BASIS_POINTS as u128 <= exact);
+                assert!(exact < (share + 1) * MAX_BASIS_POINTS as u128);
+            }
+
+            assert_eq!(apply_bps(u64::MAX, MAX_BASIS_POINTS).unwrap(), u64::MAX);
+            assert_eq!(apply_bps(u64::MAX, 0).unwrap(), 0);
+            assert!(apply_bps(1, MAX_BASIS_POINTS + 1).is_err());
+        }
+
+        #[test]
+        fn apply_bps_is_monotonic() {
+            let mut rng = Rng(0x1234_5678);
+
+            for _ in 0..ITERATIONS {
+                let a = rng.next();
+                let b = rng.next();
+                let bps = rng.bps();
+                let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+                assert!(apply_bps(low, bps).unwrap() <= apply_bps(high, bps).unwrap());
+
+                let other_bps = rng.bps();
+                let (low_bps, high_bps) = if bps <= other_bps { (bps, other_bps) } else { (other_bps, bps) };
+                assert!(apply_bps(a, low_bps).unwrap() <= apply_bps(a, high_bps).unwrap());
+            }
+        }
+
+        #[test]
+        fn increase_by_bps_overflow_is_an_error() {
+            assert!(increase_by_bps(u64::MAX, 1).is_err());
+            assert_eq!(increase_by_bps(u64::MAX, 0).unwrap(), u64::MAX);
+            assert!(checked_add(u64::MAX, 1).is_err());
+            assert!(checked_sub(0, 1).is_err());
+        }
+
+        #[test]
+        fn reverse_increase_by_bps_undoes_increase() {
+            let mut rng = Rng(0x0b5e_55ed);
+
+            for _ in 0..ITERATIONS {
+                let amount = rng.next() / 2;
+                let bps = rng.bps();
+
+                let increased = increase_by_bps(amount, bps).unwrap();
+                assert_eq!(reverse_increase_by_bps(increased, bps).unwrap(), amount);
+            }
+
+            assert_eq!(reverse_increase_by_bps(157_500_000, BID_INCREMENT_BPS).unwrap(), 150_000_000);
+            assert_eq!(reverse_increase_by_bps(u64::MAX, 0).unwrap(), u64::MAX);
+        }
+
+        #[test]
+        fn legacy_v2_prices_are_preserved() {
+            let floor_price: u64 = 3_000_000_000;
+
+            let starting_price = apply_bps(floor_price, STARTING_PRICE_BPS).unwrap();
+            assert_eq!(starting_price, 150_000_000);
+            assert_eq!(increase_by_bps(starting_price, BID_INCREMENT_BPS).unwrap(), 157_500_000);
+        }
+
+        #[test]
+        fn interpolated_price_stays_between_bounds() {
+            let mut rng = Rng(0xfeed_f00d);
+
+            for _ in 0..ITERATIONS {
+                let a = rng.next();
+                let b = rng.next();
+                let duration = rng.next() % 1_000_000_000 + 1;
+                let elapsed = rng.next() % (duration + 1);
+                let later = elapsed + (rng.next() % (duration - elapsed + 1));
+
+                let price = interpolate_price(a, b, elapsed, duration).unwrap();
+                let later_price = interpolate_price(a, b, later, duration).unwrap();
+
+                assert!(price >= a.min(b) && price <= a.max(b));
+                if a >= b {
+                    assert!(later_price <= price);
+                } else {
+                    assert!(later_price >= price);
+                }
+            }
+
+            assert_eq!(interpolate_price(100, 10, 0, 10).unwrap(), 100);
+            assert_eq!(interpolate_price(100, 10, 10, 10).unwrap(), 10);
+            assert_eq!(interpolate_price(100, 10, 5, 0).unwrap(), 10);
+        }
+    }
+}
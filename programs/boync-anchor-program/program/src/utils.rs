@@ -20,11 +20,17 @@ use mpl_token_metadata::{
 
 use mpl_token_auth_rules::payload::{Payload, PayloadType, SeedsVec};
 
-use anchor_spl::token::Transfer;
+use anchor_spl::token::{CloseAccount, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::*;
-use crate::account::{BoyncAuction2, BoyncAuction3};
+use crate::account::{
+    AuctionState, BidCostRise, BidGate, BidIncrement, BoyncAuction2, BoyncAuction3, BoyncDutchAuction,
+    BoyncBidDeposit, BoyncConfig, BoyncDefaulter, BoyncEnglishAuction, BoyncSealedAuction, BoyncUserBid, ScheduledAuction,
+    SellerSplits, TimeExtensionPolicy,
+};
+use crate::events::{BoyncBidEvent, BoyncBuyEvent, BoyncClaimEvent, BoyncDepositEvent, BoyncRefundEvent, BoyncStateEvent};
+use crate::pricing;
 
 fn build_mpl_token_metadata_instruction_with_builder<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, BoyncTokenTransfer<'info>>,
@@ -349,6 +355,8 @@ pub fn assert_auction_active(listing_config: &Account<BoyncAuction2>) -> Result<
         return err!(AuctionError::AuctionEnded);
     }
 
+    require!(listing_config.state == AuctionState::Active, AuctionError::InvalidState);
+
     Ok(())
 }
 
@@ -362,14 +370,77 @@ pub fn assert_auction_active_v3(listing_config: &Account<BoyncAuction3>) -> Resu
         return err!(AuctionError::AuctionEnded);
     }
 
+    require!(listing_config.state == AuctionState::Active, AuctionError::InvalidState);
+
+    Ok(())
+}
+
+pub fn assert_auction_active_english(listing_config: &Account<BoyncEnglishAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    if current_timestamp < listing_config.start_auction_at {
+        return err!(AuctionError::AuctionNotStarted);
+    } else if current_timestamp > listing_config.end_auction_at {
+        return err!(AuctionError::AuctionEnded);
+    }
+
+    require!(listing_config.state == AuctionState::Active, AuctionError::InvalidState);
+
     Ok(())
 }
 
+pub fn assert_auction_active_dutch(listing_config: &Account<BoyncDutchAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    if current_timestamp < listing_config.start_auction_at {
+        return err!(AuctionError::AuctionNotStarted);
+    } else if current_timestamp > listing_config.end_auction_at {
+        return err!(AuctionError::AuctionEnded);
+    }
+
+    require!(listing_config.state == AuctionState::Active, AuctionError::InvalidState);
+
+    Ok(())
+}
+
+pub fn assert_auction_active_sealed(listing_config: &Account<BoyncSealedAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    if current_timestamp < listing_config.start_auction_at {
+        return err!(AuctionError::AuctionNotStarted);
+    } else if current_timestamp > listing_config.end_auction_at {
+        return err!(AuctionError::AuctionEnded);
+    }
+
+    require!(listing_config.state == AuctionState::Active, AuctionError::InvalidState);
+
+    Ok(())
+}
+
+pub fn assert_reveal_active_sealed(listing_config: &Account<BoyncSealedAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    if current_timestamp <= listing_config.end_auction_at {
+        return err!(AuctionError::AuctionActive);
+    } else if current_timestamp > listing_config.reveal_end_at {
+        return err!(AuctionError::RevealWindowClosed);
+    }
+
+    require!(listing_config.state == AuctionState::Active, AuctionError::InvalidState);
+
+    Ok(())
+}
+
+/// Over strictly after `end_auction_at`, the same boundary `sync_auction_state` ends auctions at.
 pub fn assert_auction_over(listing_config: &Account<BoyncAuction2>) -> Result<()> {
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
 
-    if current_timestamp < listing_config.end_auction_at {
+    if current_timestamp <= listing_config.end_auction_at {
         return err!(AuctionError::AuctionActive);
     }
 
@@ -380,31 +451,1053 @@ pub fn assert_auction_over_v3(listing_config: &Account<BoyncAuction3>) -> Result
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
 
-    if current_timestamp < listing_config.end_auction_at {
+    if current_timestamp <= listing_config.end_auction_at {
         return err!(AuctionError::AuctionActive);
     }
 
     Ok(())
 }
 
-pub fn process_time_extension(listing_config: &mut Account<BoyncAuction2>) -> Result<()> {
+pub fn assert_auction_over_english(listing_config: &Account<BoyncEnglishAuction>) -> Result<()> {
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
 
     if current_timestamp <= listing_config.end_auction_at {
-        listing_config.end_auction_at += i64::from(60 * MS_IN_SEC);
+        return err!(AuctionError::AuctionActive);
     }
 
     Ok(())
 }
 
-pub fn process_time_extension_v3(listing_config: &mut Account<BoyncAuction3>) -> Result<()> {
+pub fn assert_auction_over_dutch(listing_config: &Account<BoyncDutchAuction>) -> Result<()> {
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
 
     if current_timestamp <= listing_config.end_auction_at {
-        listing_config.end_auction_at += i64::from(60 * MS_IN_SEC);
+        return err!(AuctionError::AuctionActive);
+    }
+
+    Ok(())
+}
+
+pub fn assert_auction_over_sealed(listing_config: &Account<BoyncSealedAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    if current_timestamp <= listing_config.reveal_end_at {
+        return err!(AuctionError::AuctionActive);
+    }
+
+    Ok(())
+}
+
+/// `start_at` / `end_at` are unix ms, checked against the on-chain clock.
+pub fn assert_auction_timing(start_at: i64, end_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    for timestamp in [start_at, end_at] {
+        if !(MIN_TIMESTAMP_MS..=MAX_TIMESTAMP_MS).contains(&timestamp) {
+            return err!(AuctionError::InvalidTimestamp);
+        }
+    }
+
+    require!(end_at > start_at, AuctionError::InvalidAuctionWindow);
+    require!(end_at - start_at >= MIN_AUCTION_DURATION, AuctionError::AuctionTooShort);
+    require!(end_at - start_at <= MAX_AUCTION_DURATION, AuctionError::AuctionTooLong);
+    require!(
+        start_at >= current_timestamp - MAX_START_IN_PAST,
+        AuctionError::AuctionStartInPast
+    );
+
+    Ok(())
+}
+
+/// Validation shared by `initialize_auction2` and `update_auction_config`.
+pub fn assert_auction_config(
+    start_at: i64,
+    end_at: i64,
+    reserve_price: Option<u64>,
+    buy_now_price: Option<u64>,
+    time_extension: Option<TimeExtensionPolicy>,
+    bid_increment: Option<BidIncrement>,
+) -> Result<()> {
+    assert_auction_timing(start_at, end_at)?;
+
+    if let (Some(reserve_price), Some(buy_now_price)) = (reserve_price, buy_now_price) {
+        require!(buy_now_price >= reserve_price, AuctionError::InvalidBuyNowPrice);
+    }
+
+    if let Some(time_extension) = time_extension {
+        time_extension.validate()?;
+    }
+
+    if let Some(bid_increment) = bid_increment {
+        bid_increment.validate()?;
+    }
+
+    Ok(())
+}
+
+/// Validation shared by `initialize_auction3` and `update_auction_config3`.
+pub fn assert_auction_config_v3(
+    start_at: i64,
+    end_at: i64,
+    reserve_price: Option<u64>,
+    buy_now_price: Option<u64>,
+    time_extension: Option<TimeExtensionPolicy>,
+    bid_increment: Option<BidIncrement>,
+    bid_token_cost: Option<u64>,
+    bid_cost_rise: Option<BidCostRise>,
+) -> Result<()> {
+    assert_auction_config(start_at, end_at, reserve_price, buy_now_price, time_extension, bid_increment)?;
+
+    require!(bid_token_cost.unwrap_or(V3_BID_TOKEN_COST) > 0, AuctionError::InvalidBidTokenCost);
+
+    if let Some(bid_cost_rise) = bid_cost_rise {
+        bid_cost_rise.validate()?;
+    }
+
+    Ok(())
+}
+
+/// Winner of an ended v3 auction didn't pay within the payment window.
+pub fn assert_payment_defaulted_v3(listing_config: &Account<BoyncAuction3>) -> Result<()> {
+    // Can't default on an Auction that was already claimed.
+    require!(listing_config.claimed == 0, AuctionError::AuctionClaimed);
+    require!(listing_config.state == AuctionState::Ended, AuctionError::InvalidState);
+
+    // Can't default without a winner.
+    require!(
+        listing_config.last_bidder != Pubkey::default() && listing_config.reserve_met(),
+        AuctionError::InvalidState
+    );
+
+    // Can't default while the winner can still pay.
+    require!(
+        current_timestamp_ms()? > listing_config.payment_deadline()?,
+        AuctionError::PaymentWindowOpen
+    );
+
+    Ok(())
+}
+
+/// Flags `bidder` for leaving `unpaid_bid` on `auction` unpaid.
+pub fn flag_defaulter(
+    record: &mut Account<BoyncDefaulter>,
+    bidder: Pubkey,
+    auction: Pubkey,
+    unpaid_bid: u64,
+) -> Result<()> {
+    record.bidder = bidder;
+    record.default_count = pricing::checked_add(record.default_count, 1)?;
+    record.unpaid_total = pricing::checked_add(record.unpaid_total, unpaid_bid)?;
+    record.last_auction = auction;
+    record.last_default_at = current_timestamp_ms()?;
+
+    Ok(())
+}
+
+/// Bidder has to hold what the auction is gated on, shown by the first of the instruction's remaining
+/// accounts: the bidder's token account, followed by the NFT's metadata for a collection gate.
+/// Returns the remaining accounts following the gate accounts, e.g. creators and seller splits.
+pub fn assert_bid_gate<'a, 'info>(
+    bid_gate: &BidGate,
+    bidder: &Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<&'a [AccountInfo<'info>]> {
+    let (min_amount, gate_len) = match bid_gate {
+        BidGate::Open => return Ok(remaining_accounts),
+        BidGate::Token { min_amount, .. } => (*min_amount, 1),
+        BidGate::Collection { .. } => (1, 2),
+    };
+
+    let token_account = remaining_accounts.first().ok_or(AuctionError::BidGateNotMet)?;
+    let token_account = Account::<TokenAccount>::try_from(token_account)?;
+
+    // Can't bid on someone else's holdings.
+    require!(
+        token_account.owner == *bidder && token_account.amount >= min_amount,
+        AuctionError::BidGateNotMet
+    );
+
+    match bid_gate {
+        BidGate::Token { mint, .. } => {
+            require!(token_account.mint == *mint, AuctionError::BidGateNotMet);
+        }
+        BidGate::Collection { collection } => {
+            let metadata = remaining_accounts.get(1).ok_or(AuctionError::BidGateNotMet)?;
+
+            // Can't trust a collection that the token metadata program didn't write.
+            require!(
+                *metadata.owner == mpl_token_metadata::ID,
+                AuctionError::BidGateNotMet
+            );
+
+            let metadata = Metadata::from_account_info(metadata)?;
+            require!(metadata.mint == token_account.mint, AuctionError::BidGateNotMet);
+
+            let in_collection = matches!(
+                metadata.collection,
+                Some(nft_collection) if nft_collection.verified && nft_collection.key == *collection
+            );
+            require!(in_collection, AuctionError::BidGateNotMet);
+        }
+        BidGate::Open => {}
+    }
+
+    Ok(&remaining_accounts[gate_len..])
+}
+
+/// Locks the auction's bid deposit the first time a bidder bids, later bids reuse it.
+pub fn lock_bid_deposit<'info>(
+    auction_state: &mut Account<'info, BoyncAuction3>,
+    deposit: &mut Account<'info, BoyncBidDeposit>,
+    bidder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if deposit.bidder == bidder.key() {
+        return Ok(());
+    }
+
+    let amount = auction_state.bid_deposit;
+    if amount > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: bidder.to_account_info(),
+                    to: deposit.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    deposit.auction = auction_state.key();
+    deposit.bidder = bidder.key.clone();
+    deposit.amount = amount;
+    auction_state.open_deposits = pricing::checked_add(auction_state.open_deposits, 1)?;
+
+    emit!(BoyncDepositEvent {
+        auction_pubkey: auction_state.key(),
+        bidder_pubkey: bidder.key.clone(),
+        deposit: amount,
+        label: "deposit".to_string(),
+    });
+
+    Ok(())
+}
+
+pub fn process_time_extension(listing_config: &mut Account<BoyncAuction2>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    let extension = listing_config.time_extension.extension(
+        current_timestamp,
+        listing_config.end_auction_at,
+        listing_config.extended_by,
+    );
+
+    listing_config.end_auction_at = listing_config.end_auction_at
+        .checked_add(extension)
+        .ok_or(AuctionError::NumericalOverflow)?;
+    listing_config.extended_by = listing_config.extended_by
+        .checked_add(extension)
+        .ok_or(AuctionError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+pub fn process_time_extension_v3(listing_config: &mut Account<BoyncAuction3>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    let extension = listing_config.time_extension.extension(
+        current_timestamp,
+        listing_config.end_auction_at,
+        listing_config.extended_by,
+    );
+
+    listing_config.end_auction_at = listing_config.end_auction_at
+        .checked_add(extension)
+        .ok_or(AuctionError::NumericalOverflow)?;
+    listing_config.extended_by = listing_config.extended_by
+        .checked_add(extension)
+        .ok_or(AuctionError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+pub fn process_time_extension_english(listing_config: &mut Account<BoyncEnglishAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+
+    let extension = listing_config.time_extension.extension(
+        current_timestamp,
+        listing_config.end_auction_at,
+        listing_config.extended_by,
+    );
+
+    listing_config.end_auction_at = listing_config.end_auction_at
+        .checked_add(extension)
+        .ok_or(AuctionError::NumericalOverflow)?;
+    listing_config.extended_by = listing_config.extended_by
+        .checked_add(extension)
+        .ok_or(AuctionError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/// Moves `state` along Created -> Active -> Ended as the clock passes `start_auction_at`
+/// and `end_auction_at`, emitting an event per transition. Settled and Cancelled are final.
+pub fn sync_auction_state(
+    auction_pubkey: Pubkey,
+    state: AuctionState,
+    start_auction_at: i64,
+    end_auction_at: i64,
+) -> Result<AuctionState> {
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp * MS_IN_SEC;
+    let mut state = state;
+
+    if state == AuctionState::Created && current_timestamp >= start_auction_at {
+        state = transition_auction_state(auction_pubkey, state, AuctionState::start, "start")?;
+    }
+
+    if state == AuctionState::Active && current_timestamp > end_auction_at {
+        state = transition_auction_state(auction_pubkey, state, AuctionState::end, "end")?;
+    }
+
+    Ok(state)
+}
+
+/// Syncs an auction account's `state` with the clock, see `sync_auction_state`.
+pub fn sync_scheduled_auction<T>(auction_state: &mut Account<T>) -> Result<()>
+where
+    T: ScheduledAuction + AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    let (start_at, end_at) = auction_state.schedule();
+    let state = sync_auction_state(auction_state.key(), auction_state.auction_state(), start_at, end_at)?;
+    auction_state.set_auction_state(state);
+
+    Ok(())
+}
+
+/// Closes the defaulter's deposit to the authority and returns the slashed amount.
+/// Bidders from before deposits were locked have no deposit account, there is nothing to slash then.
+pub fn slash_defaulter_deposit<'info>(
+    auction_state: &mut Account<'info, BoyncAuction3>,
+    defaulter_deposit: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<u64> {
+    if defaulter_deposit.data_is_empty() {
+        return Ok(0);
+    }
+
+    let deposit = Account::<BoyncBidDeposit>::try_from(defaulter_deposit)?;
+
+    // Can't slash another bidder's deposit.
+    require!(deposit.bidder == auction_state.last_bidder, AuctionError::PublicKeyMismatch);
+
+    let slashed_deposit = deposit.amount;
+    deposit.close(authority.clone())?;
+    auction_state.open_deposits = pricing::checked_sub(auction_state.open_deposits, 1)?;
+
+    Ok(slashed_deposit)
+}
+
+/// Closes a v2 auction back to its authority once it is settled, whichever of claim, payout and the last refund came last.
+pub fn close_settled_auction2<'info>(
+    auction_state: &mut Account<'info, BoyncAuction2>,
+    bidders_chest: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    if auction_state.settled(bidders_chest.lamports()) {
+        auction_state.close(authority.clone())?;
+    }
+
+    Ok(())
+}
+
+/// Applies `transition` to `state` and emits the state change.
+pub fn transition_auction_state(
+    auction_pubkey: Pubkey,
+    state: AuctionState,
+    transition: fn(AuctionState) -> Result<AuctionState>,
+    label: &str,
+) -> Result<AuctionState> {
+    let updated_state = transition(state)?;
+
+    emit!(BoyncStateEvent {
+        auction_pubkey,
+        previous_auction_state: state,
+        updated_auction_state: updated_state,
+        label: label.to_string(),
+    });
+
+    Ok(updated_state)
+}
+
+/// Closes an emptied token account owned by the auction state, rent goes to `destination`.
+/// Token metadata may already have closed it when transferring a pNFT out.
+pub fn close_token_account<'info>(
+    token_program: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    // Token metadata may already have closed it during a pNFT transfer.
+    if account.data_is_empty() {
+        return Ok(());
     }
 
+    // Can't close anything but a token account.
+    require_keys_eq!(*account.owner, anchor_spl::token::ID, AuctionError::NotATokenAccount);
+
+    anchor_spl::token::close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: account.clone(),
+            destination: destination.clone(),
+            authority: authority.clone(),
+        },
+        signer_seeds
+    ))
+}
+
+/* Migration helpers */
+
+/// Reads an account still in a legacy layout of `discriminator` accounts, `legacy_size` long.
+pub fn read_legacy_account<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    discriminator: [u8; 8],
+    legacy_size: usize,
+) -> Result<T> {
+    let data = account.try_borrow_data()?;
+
+    // Can't migrate an account that isn't an auction of this kind.
+    require!(
+        data.len() >= 8 && data[..8] == discriminator,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    // Can't migrate an account twice.
+    require!(data.len() == 8 + legacy_size, AuctionError::AuctionAlreadyMigrated);
+
+    T::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Grows a program owned account to `new_len`, `payer` tops up the rent.
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+
+    if rent > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
+
+/* Bid helpers */
+
+/// Bid records outlive their use once the auction is settled, or closed at settlement.
+pub fn assert_bid_record_closable(auction: &AccountInfo) -> Result<()> {
+    if auction.data_is_empty() || auction.owner != &crate::ID {
+        return Ok(());
+    }
+
+    let data = auction.try_borrow_data()?;
+
+    let settled = if let Ok(auction_state) = BoyncAuction2::try_deserialize(&mut &data[..]) {
+        // Can't close a bid that is still refundable, `refund_bid` closes it.
+        require!(auction_state.reserve_met(), AuctionError::ReserveNotMet);
+        auction_state.state == AuctionState::Settled
+    } else if let Ok(auction_state) = BoyncAuction3::try_deserialize(&mut &data[..]) {
+        auction_state.state == AuctionState::Settled
+    } else {
+        let auction_state = BoyncEnglishAuction::try_deserialize(&mut &data[..])?;
+        auction_state.state == AuctionState::Settled
+    };
+
+    // Can't close a bid record before the auction is settled.
+    require!(settled, AuctionError::InvalidState);
+
+    Ok(())
+}
+
+
+/// Current time in ms, the unit every auction timestamp is stored in.
+pub fn current_timestamp_ms() -> Result<i64> {
+    let clock = Clock::get()?;
+
+    clock
+        .unix_timestamp
+        .checked_mul(MS_IN_SEC)
+        .ok_or_else(|| AuctionError::NumericalOverflow.into())
+}
+
+/// V2 bid shared by `place_bid2` and the legacy `update_auction2`.
+/// The bid record and event are stamped from the clock, `seq` is 0 for legacy bids.
+pub fn process_bid2<'info>(
+    auction_state: &mut Account<'info, BoyncAuction2>,
+    bidder_state: &mut Account<'info, BoyncUserBid>,
+    bidder: &Signer<'info>,
+    bidders_chest: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    seq: u64,
+) -> Result<()> {
+    sync_scheduled_auction(auction_state)?;
+
+    // Can't bid on an Auction that is expired.
+    assert_auction_active(&auction_state)?;
+
+    // Can't bid on an Auction that was already claimed.
+    require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+
+    // Can't bid on an Auction you're the authority of.
+    require!(
+        auction_state.authority.key() != bidder.key(),
+        AuctionError::AuctionAuthorityBid
+    );
+
+    // Can't bid on an Auction if you're already Last Bidder
+    require!(
+        auction_state.last_bidder.key() != bidder.key(),
+        AuctionError::AuctionAlreadyLastBidder
+    );
+
+    let transfer_instruction = anchor_lang::system_program::Transfer {
+        from: bidder.to_account_info(),
+        to: bidders_chest.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new(system_program.to_account_info(), transfer_instruction);
+
+    anchor_lang::system_program::transfer(cpi_ctx, auction_state.next_bid.clone())?;
+
+    let ts = current_timestamp_ms()?;
+
+    /* Store bid state */
+    bidder_state.auction = auction_state.key();
+    bidder_state.bidder = bidder.key.clone();
+    bidder_state.bid_value = auction_state.next_bid;
+    bidder_state.ts = ts;
+
+    auction_state.last_bidder = bidder.key.clone();
+    auction_state.highest_bid = auction_state.next_bid;
+    process_time_extension(auction_state)?;
+    auction_state.next_bid = auction_state.bid_increment.next_bid(auction_state.next_bid)?;
+
+    emit!(BoyncBidEvent {
+        auction_pubkey: auction_state.key(),
+        bidder_pubkey: auction_state.last_bidder.clone(),
+        updated_bid_value: auction_state.next_bid.clone(),
+        updated_end_timestamp: auction_state.end_auction_at,
+        label: "bid".to_string(),
+        ts: ts,
+        bid_token_cost: 0,
+        price_step: pricing::checked_sub(auction_state.next_bid, auction_state.highest_bid)?,
+        seq,
+    });
+
+    Ok(())
+}
+
+/// V3 bid shared by `place_bid3` and the legacy `update_auction3`.
+/// The bid record and event are stamped from the clock, `seq` is 0 for legacy bids.
+pub fn process_bid3<'info>(
+    auction_state: &mut Account<'info, BoyncAuction3>,
+    bidder_state: &mut Account<'info, BoyncUserBid>,
+    deposit: &mut Account<'info, BoyncBidDeposit>,
+    bidder: &Signer<'info>,
+    bidder_token_account: &Account<'info, TokenAccount>,
+    chest: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    seq: u64,
+) -> Result<()> {
+    sync_scheduled_auction(auction_state)?;
+
+    // Can't bid on an Auction that is ended.
+    assert_auction_active_v3(&auction_state)?;
+
+    // Can't bid on an Auction that was already claimed.
+    require!(auction_state.claimed == 0, AuctionError::AuctionClaimed);
+
+    // Can't bid on an Auction you're the authority of.
+    require!(
+        auction_state.authority.key() != bidder.key(),
+        AuctionError::AuctionAuthorityBid
+    );
+
+    // Can't bid on an Auction if you're already Last Bidder
+    require!(
+        auction_state.last_bidder.key() != bidder.key(),
+        AuctionError::AuctionAlreadyLastBidder
+    );
+
+    // Token program instruction to send SPL token.
+    let transfer_instruction = Transfer {
+        from:       bidder_token_account.to_account_info(),
+        to:         chest.to_account_info(),
+        authority:  bidder.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), transfer_instruction);
+
+    let bid_token_cost = auction_state.next_bid_token_cost()?;
+    anchor_spl::token::transfer(cpi_ctx, bid_token_cost)?;
+
+    /* First bid locks the bidder's deposit */
+    lock_bid_deposit(auction_state, deposit, bidder, system_program)?;
+
+    /* Outbid wallet becomes the runner-up, at their last price */
+    let previous_bid = auction_state.current_bid;
+    auction_state.runner_up = auction_state.last_bidder.clone();
+    auction_state.runner_up_bid = previous_bid;
+
+    /* Increase current_bid following the auction's increment schedule */
+    auction_state.current_bid = auction_state.bid_increment.next_bid(auction_state.current_bid)?;
+    auction_state.bid_count = pricing::checked_add(auction_state.bid_count, 1)?;
+    auction_state.last_bidder = bidder.key.clone();
+    process_time_extension_v3(auction_state)?;
+
+    let ts = current_timestamp_ms()?;
+
+    /* Store bid state */
+    bidder_state.auction = auction_state.key();
+    bidder_state.bidder = bidder.key.clone();
+    bidder_state.bid_value = auction_state.current_bid;
+    bidder_state.ts = ts;
+
+    emit!(BoyncBidEvent {
+        auction_pubkey: auction_state.key(),
+        bidder_pubkey: auction_state.last_bidder.clone(),
+        updated_bid_value: auction_state.current_bid.clone(),
+        updated_end_timestamp: auction_state.end_auction_at,
+        label: "bid".to_string(),
+        ts: ts,
+        bid_token_cost,
+        price_step: pricing::checked_sub(auction_state.current_bid, previous_bid)?,
+        seq,
+    });
+
+    Ok(())
+}
+
+/// Returns the outbid English leader's bid, into their proxy escrow when they bid by proxy so
+/// the proxy can keep bidding, to their wallet otherwise.
+pub fn refund_english_leader<'info>(
+    auction_state: &Account<'info, BoyncEnglishAuction>,
+    previous_bidder: Option<&AccountInfo<'info>>,
+    leader_proxy: &AccountInfo<'info>,
+) -> Result<()> {
+    if !auction_state.has_bids() {
+        return Ok(());
+    }
+
+    let destination = if auction_state.proxy_max > 0 {
+        leader_proxy
+    } else {
+        match previous_bidder {
+            Some(previous_bidder) => previous_bidder,
+            None => return err!(AuctionError::PreviousBidderMissing),
+        }
+    };
+
+    transfer_lamports_from_pda(
+        &auction_state.to_account_info(),
+        destination,
+        auction_state.highest_bid
+    )?;
+
+    emit!(BoyncRefundEvent {
+        auction_pubkey: auction_state.key(),
+        bidder_pubkey: auction_state.last_bidder.clone(),
+        refunded_value: auction_state.highest_bid,
+        label: "refund".to_string(),
+    });
+
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Bid the program placed on behalf of a proxy bidder.
+pub fn emit_proxy_bid(
+    auction_state: &Account<BoyncEnglishAuction>,
+    bidder: Pubkey,
+    bid_value: u64,
+    previous_bid: u64,
+) -> Result<()> {
+    emit!(BoyncBidEvent {
+        auction_pubkey: auction_state.key(),
+        bidder_pubkey: bidder,
+        updated_bid_value: bid_value,
+        updated_end_timestamp: auction_state.end_auction_at,
+        label: "proxy_bid".to_string(),
+        ts: current_timestamp_ms()?,
+        bid_token_cost: 0,
+        price_step: pricing::checked_sub(bid_value, previous_bid)?,
+        seq: 0,
+    });
+
+    Ok(())
+}
+
+/// Commitment a sealed bidder submits in `commit_bid`: sha256(amount_le || salt).
+pub fn sealed_bid_commitment(amount: u64, salt: &[u8; 32]) -> [u8; 32] {
+    solana_program::hash::hashv(&[&amount.to_le_bytes(), salt]).to_bytes()
+}
+
+/* Buy now helpers */
+
+/// Settles a buy now at `price`: the buyer pays the authority, less creator royalties and the
+/// protocol fee, and receives the token, the emptied treasury is closed back to the authority.
+/// Sold before `end_auction_at`, the auction ends and settles right away, returns the settled state.
+pub fn settle_buy_now<'info>(
+    transfer_accounts: BoyncTokenTransfer<'info>,
+    token_metadata_program: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    fee_recipient: &AccountInfo<'info>,
+    config: &BoyncConfig,
+    seller_splits: &SellerSplits,
+    remaining_accounts: &[AccountInfo<'info>],
+    auction_id: i64,
+    auction_bump: u8,
+    state: AuctionState,
+    price: u64,
+) -> Result<AuctionState> {
+    let auction_pubkey = transfer_accounts.auction_state.key();
+    let buyer = transfer_accounts.payer.clone();
+    let treasury = transfer_accounts.token.clone();
+    let spl_token_program = transfer_accounts.spl_token_program.clone();
+    let system_program = transfer_accounts.system_program.clone();
+
+    /* Buyer pays the buy now price straight to the authority, less creator royalties and the protocol fee */
+    let royalties = pay_creator_royalties(
+        &system_program,
+        &buyer,
+        &transfer_accounts.metadata,
+        &transfer_accounts.mint.key(),
+        remaining_accounts,
+        price,
+        &[]
+    )?;
+
+    pay_with_protocol_fee(
+        &system_program,
+        &buyer,
+        authority,
+        seller_splits,
+        remaining_accounts,
+        fee_recipient,
+        config,
+        pricing::checked_sub(price, royalties)?,
+        &[]
+    )?;
+
+    let treasury_mint = transfer_accounts.mint.key();
+    let auction_auth = authority.key();
+    let app_idx_bytes = auction_id.to_le_bytes();
+    let seeds = &[
+        AUCTION_SEED,
+        auction_auth.as_ref(),
+        treasury_mint.as_ref(),
+        app_idx_bytes.as_ref(),
+        &[auction_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    /* Then we transfer the NFT to the buyer */
+    let auction_state = transfer_accounts.auction_state.clone();
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_metadata_program.clone(),
+        transfer_accounts,
+        signer_seeds
+    );
+
+    token_transfer(cpi_ctx, &auction_id, 1)?;
+
+    msg!("[BoyncDebug][settle_buy_now] treasury transfered token.");
+
+    /* Close the now empty treasury back to the authority */
+    close_token_account(
+        &spl_token_program,
+        &treasury,
+        authority,
+        &auction_state,
+        signer_seeds
+    )?;
+
+    let state = transition_auction_state(auction_pubkey, state, AuctionState::end, "end")?;
+    let state = transition_auction_state(auction_pubkey, state, AuctionState::settle, "settle")?;
+
+    emit!(BoyncBuyEvent {
+        auction_pubkey,
+        buyer_pubkey: buyer.key(),
+        price,
+        label: "buy_now".to_string(),
+    });
+
+    emit!(BoyncClaimEvent {
+        auction_pubkey,
+        claimed: 1,
+        label: "claim".to_string(),
+    });
+
+    Ok(state)
+}
+
+/* Lamport helpers */
+
+/// Seller share of a settlement: all of it to `seller`, or split between the auction's
+/// seller split recipients, which have to be among `split_accounts`.
+pub fn seller_payouts<'a, 'info>(
+    seller: &'a AccountInfo<'info>,
+    seller_splits: &SellerSplits,
+    split_accounts: &'a [AccountInfo<'info>],
+    amount: u64,
+) -> Result<Vec<(&'a AccountInfo<'info>, u64)>> {
+    if !seller_splits.is_set() {
+        return Ok(vec![(seller, amount)]);
+    }
+
+    let used_splits: Vec<_> = seller_splits.splits.iter().filter(|split| split.bps > 0).collect();
+    let mut remaining = amount;
+    let mut payouts = Vec::with_capacity(used_splits.len());
+
+    for (idx, split) in used_splits.iter().enumerate() {
+        // Can't pay a split recipient that wasn't passed in.
+        let account = split_accounts
+            .iter()
+            .find(|account| account.key() == split.recipient)
+            .ok_or(AuctionError::InvalidSellerSplits)?;
+
+        /* Last recipient takes the rounding dust so the payouts add up to `amount` */
+        let share = if idx == used_splits.len() - 1 {
+            remaining
+        } else {
+            let share = pricing::apply_bps(amount, split.bps)?;
+            remaining = pricing::checked_sub(remaining, share)?;
+            share
+        };
+
+        payouts.push((account, share));
+    }
+
+    Ok(payouts)
+}
+
+/// Pays `amount` out of a system account (a signer or a system owned PDA) to the seller, or its
+/// seller splits, less the protocol fee which goes to the configured fee recipient.
+/// Returns (seller share, fee).
+pub fn pay_with_protocol_fee<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    seller_splits: &SellerSplits,
+    split_accounts: &[AccountInfo<'info>],
+    fee_recipient: &AccountInfo<'info>,
+    config: &BoyncConfig,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<(u64, u64)> {
+    let (seller_share, fee) = config.split_fee(amount)?;
+
+    let mut payouts = seller_payouts(seller, seller_splits, split_accounts, seller_share)?;
+    payouts.push((fee_recipient, fee));
+
+    for (to, lamports) in payouts {
+        if lamports == 0 {
+            continue;
+        }
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: from.clone(),
+                    to: to.clone(),
+                },
+                signer_seeds
+            ),
+            lamports
+        )?;
+    }
+
+    Ok((seller_share, fee))
+}
+
+/// Same as `pay_with_protocol_fee` for lamports held by an account owned by this program.
+pub fn pay_with_protocol_fee_from_pda<'info>(
+    from: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    seller_splits: &SellerSplits,
+    split_accounts: &[AccountInfo<'info>],
+    fee_recipient: &AccountInfo<'info>,
+    config: &BoyncConfig,
+    amount: u64,
+) -> Result<(u64, u64)> {
+    let (seller_share, fee) = config.split_fee(amount)?;
+
+    for (to, lamports) in seller_payouts(seller, seller_splits, split_accounts, seller_share)? {
+        transfer_lamports_from_pda(from, to, lamports)?;
+    }
+    transfer_lamports_from_pda(from, fee_recipient, fee)?;
+
+    Ok((seller_share, fee))
+}
+
+/// Moves lamports out of an account owned by this program (e.g. an auction state PDA).
+/// Program owned accounts can't be the `from` of a system program transfer.
+pub fn transfer_lamports_from_pda<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let from_lamports = from.lamports();
+    let to_lamports = to.lamports();
+
+    **from.try_borrow_mut_lamports()? = from_lamports
+        .checked_sub(amount)
+        .ok_or(AuctionError::NumericalOverflow)?;
+    **to.try_borrow_mut_lamports()? = to_lamports
+        .checked_add(amount)
+        .ok_or(AuctionError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/* Royalty helpers */
+
+/// Royalty owed to each verified creator of `mint` on a sale of `amount`, as set by the
+/// metadata's `seller_fee_basis_points` and split by creator share.
+/// `creator_accounts` (the instruction's remaining accounts) must start with the verified
+/// creators, in the order they appear in the metadata.
+pub fn creator_royalties<'a, 'info>(
+    metadata: &AccountInfo<'info>,
+    mint: &Pubkey,
+    creator_accounts: &'a [AccountInfo<'info>],
+    amount: u64,
+) -> Result<Vec<(&'a AccountInfo<'info>, u64)>> {
+    let metadata = Metadata::from_account_info(metadata)?;
+    assert_keys_equal(metadata.mint, *mint)?;
+
+    let verified_creators: Vec<_> = metadata
+        .data
+        .creators
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|creator| creator.verified)
+        .collect();
+
+    let total_shares: u64 = verified_creators.iter().map(|creator| creator.share as u64).sum();
+    let royalty = pricing::apply_bps(amount, metadata.data.seller_fee_basis_points)?;
+    if total_shares == 0 || royalty == 0 {
+        return Ok(vec![]);
+    }
+
+    // Can't pay creators that weren't passed in.
+    require!(
+        creator_accounts.len() >= verified_creators.len(),
+        AuctionError::CreatorMismatch
+    );
+
+    let mut remaining = royalty;
+    let mut payouts = Vec::with_capacity(verified_creators.len());
+
+    for (idx, (creator, account)) in verified_creators.iter().zip(creator_accounts).enumerate() {
+        // Can't pay a creator account that isn't in the metadata.
+        require!(account.key() == creator.address, AuctionError::CreatorMismatch);
+
+        /* Last creator takes the rounding dust so the payouts add up to the royalty */
+        let share = if idx == verified_creators.len() - 1 {
+            remaining
+        } else {
+            let share = (royalty as u128)
+                .checked_mul(creator.share as u128)
+                .and_then(|v| v.checked_div(total_shares as u128))
+                .ok_or(AuctionError::NumericalOverflow)? as u64;
+            remaining = pricing::checked_sub(remaining, share)?;
+            share
+        };
+
+        payouts.push((account, share));
+    }
+
+    Ok(payouts)
+}
+
+/// Pays creator royalties on `amount` out of a system account (a signer or a system owned PDA).
+/// Returns the total paid, the seller is due `amount` less that.
+pub fn pay_creator_royalties<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    metadata: &AccountInfo<'info>,
+    mint: &Pubkey,
+    creator_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    let mut paid: u64 = 0;
+
+    for (creator, lamports) in creator_royalties(metadata, mint, creator_accounts, amount)? {
+        if lamports == 0 {
+            continue;
+        }
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: from.clone(),
+                    to: creator.clone(),
+                },
+                signer_seeds
+            ),
+            lamports
+        )?;
+
+        paid = paid.checked_add(lamports).ok_or(AuctionError::NumericalOverflow)?;
+    }
+
+    Ok(paid)
+}
+
+/// Same as `pay_creator_royalties` for lamports held by an account owned by this program.
+pub fn pay_creator_royalties_from_pda<'info>(
+    from: &AccountInfo<'info>,
+    metadata: &AccountInfo<'info>,
+    mint: &Pubkey,
+    creator_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<u64> {
+    let mut paid: u64 = 0;
+
+    for (creator, lamports) in creator_royalties(metadata, mint, creator_accounts, amount)? {
+        transfer_lamports_from_pda(from, creator, lamports)?;
+
+        paid = paid.checked_add(lamports).ok_or(AuctionError::NumericalOverflow)?;
+    }
+
+    Ok(paid)
+}
@@ -0,0 +1,358 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod utils;
+
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    sysvar::clock::Clock,
+};
+
+use utils::*;
+
+use anchor_lang::*;
+use boync_anchor_program::account::AuctionState;
+use solana_program::program_pack::Pack;
+use spl_token::state::Account;
+use std::result::Result as StdResult;
+
+mod auction_state_transitions {
+
+    use super::*;
+
+    const ALL_STATES: [AuctionState; 5] = [
+        AuctionState::Created,
+        AuctionState::Active,
+        AuctionState::Ended,
+        AuctionState::Settled,
+        AuctionState::Cancelled,
+    ];
+
+    #[test]
+    fn happy_path() {
+        let state = AuctionState::create();
+
+        let state = state.start().unwrap();
+        assert!(state == AuctionState::Active);
+
+        let state = state.end().unwrap();
+        assert!(state == AuctionState::Ended);
+        assert!(state.is_over());
+
+        let state = state.settle().unwrap();
+        assert!(state == AuctionState::Settled);
+        assert!(state.is_over());
+    }
+
+    #[test]
+    fn only_unsettled_running_auctions_can_be_cancelled() {
+        for state in ALL_STATES {
+            let cancelled = state.cancel();
+
+            match state {
+                AuctionState::Created | AuctionState::Active => {
+                    assert!(cancelled.unwrap() == AuctionState::Cancelled)
+                }
+                _ => assert!(cancelled.is_err()),
+            }
+        }
+    }
+
+    #[test]
+    fn final_states_reject_every_transition() {
+        for state in [AuctionState::Settled, AuctionState::Cancelled] {
+            assert!(state.start().is_err());
+            assert!(state.end().is_err());
+            assert!(state.settle().is_err());
+            assert!(state.cancel().is_err());
+            assert!(state == AuctionState::Settled || !state.is_over());
+        }
+    }
+
+    #[test]
+    fn settle_requires_ended() {
+        for state in ALL_STATES {
+            assert_eq!(state.settle().is_ok(), state == AuctionState::Ended);
+        }
+    }
+}
+
+mod auction_state_instructions {
+
+    use anchor_lang::prelude::Pubkey;
+    use boync_anchor_program::errors::AuctionError;
+    use mpl_token_metadata::{instruction::TransferArgs, state::TokenStandard};
+    use solana_program_test::ProgramTestContext;
+    use spl_associated_token_account::get_associated_token_address;
+
+    use super::*;
+
+    pub async fn setup_transfer_token(
+        context: &mut ProgramTestContext,
+        token_standard: TokenStandard,
+        amount: u64,
+    ) -> StdResult<(DigitalAsset, Pubkey, Keypair), BanksClientError> {
+        let mut da = DigitalAsset::new();
+        da.create_and_mint(context, token_standard, None, None, 1)
+            .await
+            .unwrap();
+
+        let destination_owner = Keypair::new();
+        let destination_token =
+            get_associated_token_address(&destination_owner.pubkey(), &da.mint.pubkey());
+        airdrop(context, &destination_owner.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        let authority = &Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        let args = TransferArgs::V1 {
+            authorization_data: None,
+            amount,
+        };
+
+        let params = TransferFromParams {
+            context,
+            authority,
+            source_owner: &authority.pubkey(),
+            destination_owner: destination_owner.pubkey(),
+            destination_token: None,
+            authorization_rules: None,
+            payer: authority,
+            args,
+        };
+
+        da.transfer_from(params).await.unwrap();
+
+        let token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(destination_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+
+        assert_eq!(token_account.amount, 1);
+
+        Ok((da, destination_token, destination_owner))
+    }
+
+    async fn current_timestamp(context: &mut ProgramTestContext) -> i64 {
+        context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC
+    }
+
+    #[tokio::test]
+    async fn bid_starts_end_ends_and_claim_settles() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let start_at = current_timestamp(&mut context).await;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &start_at,
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &start_at,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Created);
+
+        let player1 = Keypair::new();
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        /* First bid after start_at opens the auction */
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Active);
+
+        /* Paying out an Active auction fails, the state doesn't move */
+        let (_, tx) = boync_end(
+            &mut context,
+            &da,
+            &auction,
+            &start_at,
+            &fee_recipient.pubkey(),
+            &[creator],
+            &destination_owner,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidState);
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Active);
+
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        /* Past end_auction_at, `end` moves the auction to Ended */
+        context.get_new_latest_blockhash().await.unwrap();
+        let (_, tx) = boync_end(
+            &mut context,
+            &da,
+            &auction,
+            &start_at,
+            &fee_recipient.pubkey(),
+            &[creator],
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Ended);
+
+        /* Claiming the token settles it */
+        let player1_token = get_associated_token_address(&player1.pubkey(), &da.mint.pubkey());
+        let (_, tx) = boync_update_auction_claim(
+            &mut context,
+            &da,
+            &auction,
+            &bidders_chest,
+            &treasury,
+            &destination_owner.pubkey(),
+            &player1_token,
+            &player1,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Settled);
+
+        /* Settled is final */
+        let (_, tx) = boync_cancel_auction(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_token,
+            &destination_owner,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Settled);
+    }
+
+    #[tokio::test]
+    async fn cancel_before_start_is_final() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        /* Listed 10 minutes ahead */
+        let start_at = current_timestamp(&mut context).await + 600 * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &start_at,
+        );
+
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &start_at,
+            &destination_token,     // creator token
+            None,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+
+        /* Bidding before start_at fails, the auction stays Created */
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionNotStarted);
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Created);
+
+        let (_, tx) = boync_cancel_auction(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_token,
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Cancelled);
+
+        /* Token is back with the authority */
+        let destination_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(destination_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(destination_token_account.amount, 1);
+
+        /* Cancelled is final, not even start_at reopens it */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        context.get_new_latest_blockhash().await.unwrap();
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert!(auction_house_data.state == AuctionState::Cancelled);
+    }
+}
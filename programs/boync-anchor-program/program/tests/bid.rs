@@ -20,6 +20,9 @@ mod update_auction_bid {
     use std::println;
 
     use anchor_lang::prelude::Pubkey;
+    use boync_anchor_program::account::{AuctionState, BidGate};
+    use boync_anchor_program::errors::AuctionError;
+    use boync_anchor_program::utils::sealed_bid_commitment;
     use mpl_token_metadata::{instruction::TransferArgs, state::TokenStandard};
     use solana_program_test::ProgramTestContext;
     use spl_associated_token_account::get_associated_token_address;
@@ -172,10 +175,90 @@ mod update_auction_bid {
         assert_eq!(bidder, bidder_state_data.bidder);
         assert_eq!(auction, bidder_state_data.auction);
         assert_eq!(150_000_000, bidder_state_data.bid_value);
-        assert_eq!(ts, bidder_state_data.ts);
+        // Stored timestamp comes from the clock, not the client supplied `ts`
+        assert!(bidder_state_data.ts >= ts - MS_IN_SEC && bidder_state_data.ts < ts);
 
     }
 
+    #[tokio::test]
+    async fn boync_place_bid2_sequence() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            None
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let player2 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player2.pubkey(), ONE_SOL).await.unwrap();
+
+        let (first_bid, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player2, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let (second_bid, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &2);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Player 1 has one bid record per sequence number
+           * Bid records are stamped with the clock
+           * Player 1's counter tracks both bids
+        */
+
+        let first_bid_data = boync_get_bidder_state_data(&mut context, &first_bid.bidder_state).await;
+        assert_eq!(player1.pubkey(), first_bid_data.bidder);
+        assert_eq!(150_000_000, first_bid_data.bid_value);
+        assert!(first_bid_data.ts >= current_timestamp);
+
+        let second_bid_data = boync_get_bidder_state_data(&mut context, &second_bid.bidder_state).await;
+        assert_eq!(player1.pubkey(), second_bid_data.bidder);
+        assert_eq!(165_375_000, second_bid_data.bid_value);
+        assert!(second_bid_data.ts >= first_bid_data.ts);
+
+        let counter_data = boync_get_bidder_counter_data(&mut context, &second_bid.bidder_counter).await;
+        assert_eq!(auction, counter_data.auction);
+        assert_eq!(player1.pubkey(), counter_data.bidder);
+        assert_eq!(2, counter_data.bid_count);
+
+        /* A record can't be replayed under an already used sequence number */
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player2, &1);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+    }
+
     #[tokio::test]
     async fn boync_user_bid_v3() {
         let mut context = program_test().start_with_context().await;
@@ -227,6 +310,9 @@ mod update_auction_bid {
             &bidders_chest,
             &current_timestamp,
             &destination_token,   // creator token
+            None,
+            None,
+            None,
             None
         );
 
@@ -327,7 +413,1187 @@ mod update_auction_bid {
         assert_eq!(bidder, bidder_state_data.bidder);
         assert_eq!(auction, bidder_state_data.auction);
         assert_eq!(auction_house_data.current_bid, bidder_state_data.bid_value);
-        assert_eq!(ts, bidder_state_data.ts);
+        // Stored timestamp comes from the clock, not the client supplied `ts`
+        assert!(bidder_state_data.ts >= ts - MS_IN_SEC && bidder_state_data.ts < ts);
+
+    }
+
+    #[tokio::test]
+    async fn boync_user_place_bid_outbid_refund() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        let (_, tx) = boync_initialize_english(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // starting price
+            ONE_SOL / 100,          // min bid increment
+            None,
+            None,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let player2 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player2.pubkey(), ONE_SOL).await.unwrap();
+
+        let ts = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC + MS_IN_SEC;
+
+        let auction_balance = context.banks_client.get_balance(auction).await.unwrap();
+
+        /* Player 1 opens with 0.2 SOL */
+        let (bid_accounts, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            None,
+            &player1,
+            &0,
+            2 * ONE_SOL / 10,
+            &[],
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Stored timestamp comes from the clock, not the client supplied `ts`
+        let bidder_state_data = boync_get_bidder_state_data(&mut context, &bid_accounts.bidder_state).await;
+        assert!(bidder_state_data.ts >= ts - MS_IN_SEC && bidder_state_data.ts < ts);
+
+        let player1_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+
+        /* Player 2 bids below the minimum next bid */
+        let (_, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            Some(&player1.pubkey()),
+            &player2,
+            &0,
+            2 * ONE_SOL / 10,
+            &[],
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Player 2 can't outbid without refunding player 1 */
+        let (_, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            None,
+            &player2,
+            &0,
+            4 * ONE_SOL / 10,
+            &[],
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::PreviousBidderMissing);
+
+        /* Player 2 outbids player 1 with 0.5 SOL */
+        let (_, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            Some(&player1.pubkey()),
+            &player2,
+            &0,
+            5 * ONE_SOL / 10,
+            &[],
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Player 1 has been refunded the outbid amount
+           * Only the highest bid is escrowed in `auction`
+           * Update `auction` account
+        */
+        let player1_refunded_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+        assert_eq!(player1_refunded_balance, player1_balance + 2 * ONE_SOL / 10);
+
+        let escrowed_balance = context.banks_client.get_balance(auction).await.unwrap();
+        assert_eq!(escrowed_balance, auction_balance + 5 * ONE_SOL / 10);
+
+        let auction_house_data = boync_get_auction_data_english(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, player2.pubkey());
+        assert_eq!(auction_house_data.highest_bid, 5 * ONE_SOL / 10);
+        assert_eq!(auction_house_data.min_next_bid().unwrap(), 5 * ONE_SOL / 10 + ONE_SOL / 100);
+
+        /* Player 1 bids again, the new bid gets its own record */
+        let (rebid_accounts, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            Some(&player2.pubkey()),
+            &player1,
+            &1,
+            6 * ONE_SOL / 10,
+            &[],
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let bidder_state_data = boync_get_bidder_state_data(&mut context, &bid_accounts.bidder_state).await;
+        assert_eq!(bidder_state_data.bid_value, 2 * ONE_SOL / 10);
+
+        let bidder_state_data = boync_get_bidder_state_data(&mut context, &rebid_accounts.bidder_state).await;
+        assert_eq!(bidder_state_data.bid_value, 6 * ONE_SOL / 10);
+    }
+
+    #[tokio::test]
+    async fn boync_proxy_bids_resolve_in_bid() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        let (_, tx) = boync_initialize_english(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // starting price
+            ONE_SOL / 100,          // min bid increment
+            None,
+            None,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let player2 = Keypair::new();
+        let player3 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player2.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player3.pubkey(), ONE_SOL).await.unwrap();
+
+        let auction_balance = context.banks_client.get_balance(auction).await.unwrap();
+
+        /* Player 1 escrows up to 0.5 SOL, the proxy opens at the starting price */
+        let (_, tx) = boync_place_proxy_bid(
+            &mut context,
+            &auction,
+            None,
+            &player1,
+            5 * ONE_SOL / 10,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data_english(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, player1.pubkey());
+        assert_eq!(auction_house_data.highest_bid, ONE_SOL / 10);
+        assert_eq!(auction_house_data.proxy_max, 5 * ONE_SOL / 10);
+
+        /* Player 2 bids 0.2 SOL by hand, player 1's proxy answers with the minimum outbidding bid */
+        let player2_balance = context.banks_client.get_balance(player2.pubkey()).await.unwrap();
+
+        let (bid_accounts, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            Some(&player1.pubkey()),
+            &player2,
+            &0,
+            2 * ONE_SOL / 10,
+            &[],
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data_english(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, player1.pubkey());
+        assert_eq!(auction_house_data.highest_bid, 2 * ONE_SOL / 10 + ONE_SOL / 100);
+
+        /* Player 2 only paid the fee and the bid record rent, the record holds no live bid */
+        let player2_refunded_balance = context.banks_client.get_balance(player2.pubkey()).await.unwrap();
+        assert!(player2_refunded_balance > player2_balance - ONE_SOL / 100);
+
+        let bidder_state_data = boync_get_bidder_state_data(&mut context, &bid_accounts.bidder_state).await;
+        assert_eq!(bidder_state_data.bid_value, 0);
+
+        /* Player 3 escrows up to 0.8 SOL, player 1's proxy runs out at its maximum */
+        let (_, tx) = boync_place_proxy_bid(
+            &mut context,
+            &auction,
+            Some(&player1.pubkey()),
+            &player3,
+            8 * ONE_SOL / 10,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data_english(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, player3.pubkey());
+        assert_eq!(auction_house_data.highest_bid, 5 * ONE_SOL / 10 + ONE_SOL / 100);
+        assert_eq!(auction_house_data.proxy_max, 8 * ONE_SOL / 10);
+
+        /* Only the highest bid is escrowed in `auction` */
+        let escrowed_balance = context.banks_client.get_balance(auction).await.unwrap();
+        assert_eq!(escrowed_balance, auction_balance + 5 * ONE_SOL / 10 + ONE_SOL / 100);
+
+        /* Leader's escrow backs the highest bid */
+        let (_, tx) = boync_withdraw_proxy_bid(&mut context, &auction, &player3);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Outbid player 1 takes the whole escrow back */
+        let player1_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+
+        let (withdraw_accounts, tx) = boync_withdraw_proxy_bid(&mut context, &auction, &player1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        assert!(context.banks_client.get_account(withdraw_accounts.proxy).await.unwrap().is_none());
+        let player1_refunded_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+        assert!(player1_refunded_balance > player1_balance + 5 * ONE_SOL / 10);
+    }
+
+    #[tokio::test]
+    async fn boync_token_gated_bid() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        // Creating the gate token mint
+        let gate_mint_key = Keypair::new();
+        create_mint(&mut context, &gate_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        /* Only holders of at least 2 gate tokens can bid */
+        let (_, tx) = boync_initialize_english(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // starting price
+            ONE_SOL / 100,          // min bid increment
+            None,
+            Some(BidGate::Token { mint: gate_mint_key.pubkey(), min_amount: 2 }),
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+
+        let gate_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &gate_token_account,
+            &gate_mint_key.pubkey(),
+            &player1.pubkey(),
+        )
+        .await.unwrap();
+        mint_tokens(
+            &mut context,
+            &gate_mint_key.pubkey(),
+            &gate_token_account.pubkey(),
+            1,
+            &payer_wallet.pubkey(),
+            Some(&payer_wallet),
+        )
+        .await.unwrap();
+
+        /* Bids without the gate token account are rejected */
+        let (_, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            None,
+            &player1,
+            &0,
+            ONE_SOL / 10,
+            &[],
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Holding a single gate token isn't enough */
+        let (_, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            None,
+            &player1,
+            &0,
+            ONE_SOL / 10,
+            &[gate_token_account.pubkey()],
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        mint_tokens(
+            &mut context,
+            &gate_mint_key.pubkey(),
+            &gate_token_account.pubkey(),
+            1,
+            &payer_wallet.pubkey(),
+            Some(&payer_wallet),
+        )
+        .await.unwrap();
+
+        context.get_new_latest_blockhash().await.unwrap();
+        let (_, tx) = boync_place_bid(
+            &mut context,
+            &auction,
+            None,
+            &player1,
+            &0,
+            ONE_SOL / 10,
+            &[gate_token_account.pubkey()],
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data_english(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, player1.pubkey());
+        assert_eq!(auction_house_data.highest_bid, ONE_SOL / 10);
+    }
+
+    #[tokio::test]
+    async fn boync_token_gated_buy_now() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        // Creating the gate token mint
+        let gate_mint_key = Keypair::new();
+        create_mint(&mut context, &gate_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        /* Only gate token holders can buy it now */
+        let (_, tx) = boync_initialize_english(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // starting price
+            ONE_SOL / 100,          // min bid increment
+            Some(ONE_SOL),          // buy now price
+            Some(BidGate::Token { mint: gate_mint_key.pubkey(), min_amount: 1 }),
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        let buyer = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &buyer.pubkey(), 2 * ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        /* Buying without the gate token account is rejected */
+        let (_, tx) = boync_buy_now_english(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[creator],
+            None,
+            &buyer,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::BidGateNotMet);
+
+        let gate_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &gate_token_account,
+            &gate_mint_key.pubkey(),
+            &buyer.pubkey(),
+        )
+        .await.unwrap();
+        mint_tokens(
+            &mut context,
+            &gate_mint_key.pubkey(),
+            &gate_token_account.pubkey(),
+            1,
+            &payer_wallet.pubkey(),
+            Some(&payer_wallet),
+        )
+        .await.unwrap();
+
+        /* Gate accounts come first, creators follow */
+        let (_, tx) = boync_buy_now_english(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[gate_token_account.pubkey()],
+            &[creator],
+            None,
+            &buyer,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data_english(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, buyer.pubkey());
+        assert_eq!(auction_house_data.highest_bid, ONE_SOL);
+        assert!(auction_house_data.state == AuctionState::Settled);
+    }
+
+    /* Lists the asset in a Dutch auction starting now and sets a 2.5% protocol fee */
+    async fn setup_dutch_auction(
+        context: &mut ProgramTestContext,
+        start_price: u64,
+        floor_price: u64,
+        auction_duration: i64,
+    ) -> (DigitalAsset, Keypair, Pubkey, Pubkey, Pubkey) {
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(context, TokenStandard::ProgrammableNonFungible, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        let (_, tx) = boync_initialize_dutch(
+            context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            start_price,
+            floor_price,
+            auction_duration,
+            None,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        (da, destination_owner, auction, treasury, fee_recipient.pubkey())
+    }
+
+    #[tokio::test]
+    async fn boync_buy_dutch_price_decays() {
+        let mut context = program_test().start_with_context().await;
+
+        let (da, authority, auction, treasury, fee_recipient) =
+            setup_dutch_auction(&mut context, ONE_SOL, ONE_SOL / 10, THIRTY_MINUTES_IN_MSEC).await;
+
+        /* Test assets carry a 5% royalty to a single verified creator, the context payer */
+        let creator = context.payer.pubkey();
+
+        let buyer = Keypair::new();
+        let late_buyer = Keypair::new();
+        airdrop(&mut context, &buyer.pubkey(), 2 * ONE_SOL).await.unwrap();
+        airdrop(&mut context, &late_buyer.pubkey(), 2 * ONE_SOL).await.unwrap();
+
+        /* Warp blockchain forward, about a third of the auction */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let authority_balance = context.banks_client.get_balance(authority.pubkey()).await.unwrap();
+        let state_rent = context.banks_client.get_balance(auction).await.unwrap();
+        let auction_house_data = boync_get_auction_data_dutch(&mut context, &auction).await;
+
+        let (_, tx) = boync_buy_dutch(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &authority.pubkey(),
+            &fee_recipient,
+            &[],
+            &[creator],
+            &buyer,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Buyer paid the decayed price, above the floor
+           * Buyer received the token
+           * Authority received the price, less royalties and the protocol fee
+           * Auction is sold, settled and closed back to the authority
+        */
+        let now = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let sold_price = auction_house_data.current_price(now).unwrap();
+        assert!(sold_price < ONE_SOL);
+        assert!(sold_price > ONE_SOL / 10);
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+
+        let buyer_token = get_associated_token_address(&buyer.pubkey(), &da.mint.pubkey());
+        let buyer_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(buyer_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(buyer_token_account.amount, 1);
+
+        let royalties = sold_price * 500 / 10_000;
+        let fee = (sold_price - royalties) * 250 / 10_000;
+        let treasury_rent = context.banks_client.get_rent().await.unwrap().minimum_balance(spl_token::state::Account::LEN);
+        let sold_authority_balance = context.banks_client.get_balance(authority.pubkey()).await.unwrap();
+        assert!(sold_authority_balance >= authority_balance + sold_price - royalties - fee + treasury_rent + state_rent - 1);
+
+        /* Treasury was closed back to the authority */
+        assert!(context.banks_client.get_account(treasury).await.unwrap().is_none());
+
+        /* Nothing left to buy */
+        let (_, tx) = boync_buy_dutch(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &authority.pubkey(),
+            &fee_recipient,
+            &[],
+            &[creator],
+            &late_buyer,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        let late_buyer_balance = context.banks_client.get_balance(late_buyer.pubkey()).await.unwrap();
+        assert_eq!(late_buyer_balance, 2 * ONE_SOL);
+    }
+
+    #[tokio::test]
+    async fn boync_buy_dutch_at_floor_price() {
+        let mut context = program_test().start_with_context().await;
+
+        /* Start price at the floor, the price can't decay any further */
+        let (da, authority, auction, treasury, fee_recipient) =
+            setup_dutch_auction(&mut context, ONE_SOL / 2, ONE_SOL / 2, THIRTY_MINUTES_IN_MSEC).await;
+
+        let creator = context.payer.pubkey();
+
+        let buyer = Keypair::new();
+        airdrop(&mut context, &buyer.pubkey(), 2 * ONE_SOL).await.unwrap();
+
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let (_, tx) = boync_buy_dutch(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &authority.pubkey(),
+            &fee_recipient,
+            &[],
+            &[creator],
+            &buyer,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Buyer paid the floor price, the settled state is closed */
+        let buyer_balance = context.banks_client.get_balance(buyer.pubkey()).await.unwrap();
+        assert!(buyer_balance < 2 * ONE_SOL - ONE_SOL / 2);
+        assert!(buyer_balance > 2 * ONE_SOL - ONE_SOL / 2 - ONE_SOL / 100);
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_buy_now_without_bids() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        let (_, tx) = boync_initialize_2_with(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            None,
+            Auction2Options {
+                buy_now_price: Some(ONE_SOL),
+                ..Auction2Options::default()
+            },
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        let buyer = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &buyer.pubkey(), 2 * ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        /* Authority can't buy its own auction */
+        let (_, tx) = boync_buy_now(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[creator],
+            &destination_owner,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionAuthorityBid);
+
+        let authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        let fee_recipient_balance = context.banks_client.get_balance(fee_recipient.pubkey()).await.unwrap();
+
+        let (_, tx) = boync_buy_now(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[creator],
+            &buyer,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Buyer received the token
+           * Authority received the price, less royalties and the protocol fee
+           * Auction is sold and settled at the buy now price
+        */
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert_eq!(auction_house_data.highest_bid, ONE_SOL);
+        assert_eq!(auction_house_data.last_bidder, buyer.pubkey());
+        assert_eq!(auction_house_data.claimed, 1);
+        assert!(auction_house_data.state == AuctionState::Settled);
+
+        let buyer_token = get_associated_token_address(&buyer.pubkey(), &da.mint.pubkey());
+        let buyer_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(buyer_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(buyer_token_account.amount, 1);
+
+        let royalties = ONE_SOL * 500 / 10_000;
+        let fee = (ONE_SOL - royalties) * 250 / 10_000;
+        let treasury_rent = context.banks_client.get_rent().await.unwrap().minimum_balance(spl_token::state::Account::LEN);
+        let sold_authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        assert!(sold_authority_balance >= authority_balance + ONE_SOL - royalties - fee + treasury_rent);
+
+        let sold_fee_recipient_balance = context.banks_client.get_balance(fee_recipient.pubkey()).await.unwrap();
+        assert_eq!(sold_fee_recipient_balance, fee_recipient_balance + fee);
+
+        /* Treasury was closed back to the authority */
+        assert!(context.banks_client.get_account(treasury).await.unwrap().is_none());
+
+        /* Bidding is over once bought */
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &buyer, &1);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn boync_buy_now_english_refunds_outbid_leader() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        let (_, tx) = boync_initialize_english(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // starting price
+            ONE_SOL / 100,          // min bid increment
+            Some(ONE_SOL),          // buy now price
+            None,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        let player1 = Keypair::new();
+        let player2 = Keypair::new();
+        let buyer = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player2.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &buyer.pubkey(), 2 * ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        /* Player 2 outbids player 1 */
+        let (_, tx) = boync_place_bid(&mut context, &auction, None, &player1, &0, ONE_SOL / 10, &[]);
+        context.banks_client.process_transaction(tx).await.unwrap();
+        let (_, tx) = boync_place_bid(&mut context, &auction, Some(&player1.pubkey()), &player2, &0, 2 * ONE_SOL / 10, &[]);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* The leader's bid has to be refunded */
+        let (_, tx) = boync_buy_now_english(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[creator],
+            None,
+            &buyer,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::PreviousBidderMissing);
+
+        /* ... to the leader only */
+        let (_, tx) = boync_buy_now_english(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[creator],
+            Some(&player1.pubkey()),
+            &buyer,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::PublicKeyMismatch);
+
+        let auction_balance = context.banks_client.get_balance(auction).await.unwrap();
+        let player2_balance = context.banks_client.get_balance(player2.pubkey()).await.unwrap();
+
+        let (_, tx) = boync_buy_now_english(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[creator],
+            Some(&player2.pubkey()),
+            &buyer,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Outbid leader got the escrowed bid back
+           * Buyer received the token
+           * Auction is sold and settled at the buy now price
+        */
+        let refunded_balance = context.banks_client.get_balance(player2.pubkey()).await.unwrap();
+        assert_eq!(refunded_balance, player2_balance + 2 * ONE_SOL / 10);
+        let escrowed_balance = context.banks_client.get_balance(auction).await.unwrap();
+        assert_eq!(escrowed_balance, auction_balance - 2 * ONE_SOL / 10);
+
+        let auction_house_data = boync_get_auction_data_english(&mut context, &auction).await;
+        assert_eq!(auction_house_data.highest_bid, ONE_SOL);
+        assert_eq!(auction_house_data.last_bidder, buyer.pubkey());
+        assert_eq!(auction_house_data.claimed, 1);
+        assert!(auction_house_data.state == AuctionState::Settled);
+
+        let buyer_token = get_associated_token_address(&buyer.pubkey(), &da.mint.pubkey());
+        let buyer_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(buyer_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(buyer_token_account.amount, 1);
+
+        /* Bidding is over once bought */
+        let (_, tx) = boync_place_bid(&mut context, &auction, Some(&buyer.pubkey()), &player1, &1, ONE_SOL / 2, &[]);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn boync_buy_now_v3_after_auction_end_fails() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        // Creating NLT token mint
+        let nlt_mint_key = Keypair::new();
+        create_mint(&mut context, &nlt_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas_with_token_mint(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &nlt_mint_key.pubkey(),
+            &current_timestamp
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_3(
+            &mut context,
+            &destination_owner,
+            &da,
+            &nlt_mint_key.pubkey(),
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,   // creator token
+            Some(&auction_duration),
+            None,
+            None,
+            Some(ONE_SOL)         // buy now price
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        let buyer = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &buyer.pubkey(), 2 * ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let (_, tx) = boync_buy_now3(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[creator],
+            &buyer,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionEnded);
+
+        /* Buyer paid nothing, the token is still in the treasury */
+        let buyer_balance = context.banks_client.get_balance(buyer.pubkey()).await.unwrap();
+        assert_eq!(buyer_balance, 2 * ONE_SOL);
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert_eq!(auction_house_data.claimed, 0);
+        assert_eq!(auction_house_data.last_bidder, solana_program::system_program::id());
+    }
+
+    #[tokio::test]
+    async fn boync_sealed_reveal_checks() {
+        let mut context = program_test().start_with_context().await;
+
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, TokenStandard::ProgrammableNonFungible, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        /* 5 minutes to commit, then 15 minutes to reveal */
+        let (_, tx) = boync_initialize_sealed(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // starting price
+            ONE_SOL / 10,           // min deposit
+            1000,                   // 10% of unrevealed deposits are forfeited
+            300 * MS_IN_SEC,
+            900 * MS_IN_SEC,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let player2 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player2.pubkey(), ONE_SOL).await.unwrap();
+
+        let salt1 = [1u8; 32];
+        let salt2 = [2u8; 32];
+
+        let (_, tx) = boync_commit_bid(
+            &mut context,
+            &auction,
+            &player1,
+            sealed_bid_commitment(5 * ONE_SOL / 10, &salt1),
+            2 * ONE_SOL / 10,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let (_, tx) = boync_commit_bid(
+            &mut context,
+            &auction,
+            &player2,
+            sealed_bid_commitment(3 * ONE_SOL / 10, &salt2),
+            2 * ONE_SOL / 10,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Bids can't be revealed while the commit window is open */
+        let (_, tx) = boync_reveal_bid(&mut context, &auction, &player1, 5 * ONE_SOL / 10, salt1);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionActive);
+
+        /* Warp blockchain forward, into the reveal window */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+        context.get_new_latest_blockhash().await.unwrap();
+
+        /* Reveal has to match the commitment, both amount and salt */
+        let (_, tx) = boync_reveal_bid(&mut context, &auction, &player1, 4 * ONE_SOL / 10, salt1);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidCommitment);
+
+        let (_, tx) = boync_reveal_bid(&mut context, &auction, &player1, 5 * ONE_SOL / 10, salt2);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidCommitment);
+
+        let player1_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+
+        let (_, tx) = boync_reveal_bid(&mut context, &auction, &player1, 5 * ONE_SOL / 10, salt1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Escrow was topped up to the revealed amount */
+        let bid_data = boync_get_sealed_bid_data(&mut context, &auction, &player1.pubkey()).await;
+        assert_eq!(bid_data.revealed, 1);
+        assert_eq!(bid_data.bid_value, 5 * ONE_SOL / 10);
+        assert_eq!(bid_data.deposit, 5 * ONE_SOL / 10);
+
+        let revealed_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+        assert!(revealed_balance <= player1_balance - 3 * ONE_SOL / 10);
+
+        /* A bid is revealed once */
+        context.get_new_latest_blockhash().await.unwrap();
+        let (_, tx) = boync_reveal_bid(&mut context, &auction, &player1, 5 * ONE_SOL / 10, salt1);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::BidAlreadyRevealed);
+
+        /* Warp blockchain forward, past the reveal window */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let (_, tx) = boync_reveal_bid(&mut context, &auction, &player2, 3 * ONE_SOL / 10, salt2);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::RevealWindowClosed);
 
+        let auction_house_data = boync_get_auction_data_sealed(&mut context, &auction).await;
+        assert_eq!(auction_house_data.highest_bid, 5 * ONE_SOL / 10);
+        assert_eq!(auction_house_data.second_bid, 0);
+        assert_eq!(auction_house_data.last_bidder, player1.pubkey());
     }
 }
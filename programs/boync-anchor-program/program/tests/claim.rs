@@ -20,6 +20,11 @@ mod update_auction_claim {
     // use std::println;
 
     use anchor_lang::prelude::Pubkey;
+    use boync_anchor_program::account::{AuctionState, SellerSplit, SellerSplits};
+    use boync_anchor_program::constants::MAX_CRANK_FEE_BPS;
+    use boync_anchor_program::errors::AuctionError;
+    use boync_anchor_program::pda::find_boync_sealed_bid_address;
+    use boync_anchor_program::utils::sealed_bid_commitment;
     use mpl_token_metadata::{instruction::TransferArgs, state::TokenStandard};
     use solana_program_test::ProgramTestContext;
     use spl_associated_token_account::get_associated_token_address;
@@ -206,7 +211,8 @@ mod update_auction_claim {
         assert_eq!(bidder, bidder_state_data.bidder);
         assert_eq!(auction, bidder_state_data.auction);
         assert_eq!(150_000_000, bidder_state_data.bid_value);
-        assert_eq!(ts, bidder_state_data.ts);
+        // Stored timestamp comes from the clock, not the client supplied `ts`
+        assert!(bidder_state_data.ts >= ts - MS_IN_SEC && bidder_state_data.ts < ts);
 
         /* Warp blockchain forward */
         let current_slot = context.banks_client.get_root_slot().await.unwrap();
@@ -229,7 +235,9 @@ mod update_auction_claim {
             &mut context,
             &da,
             &auction,
+            &bidders_chest,
             &treasury,
+            &destination_owner.pubkey(),
             &player1_token,
             &player1,
         );
@@ -253,10 +261,33 @@ mod update_auction_claim {
 
         /* Winner received token */
         assert_eq!(player1_token_account.amount, 1);
+
+        /* State is kept until the proceeds are paid out, then closed by `end` */
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+        let (_, tx) = boync_end(
+            &mut context,
+            &da,
+            &auction,
+            &current_timestamp,
+            &fee_recipient.pubkey(),
+            &[creator],
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
     }
 
     #[tokio::test]
-    async fn boync_user_claim_v3() {
+    async fn boync_keeper_settle() {
         let mut context = program_test().start_with_context().await;
 
         let token_standard = TokenStandard::ProgrammableNonFungible;
@@ -265,110 +296,118 @@ mod update_auction_claim {
                 .await
                 .unwrap();
 
-        let payer_wallet = Keypair::new();
-        airdrop(&mut context, &payer_wallet.pubkey(), 10_000_000_000)
-            .await
-            .unwrap();
-
-        // Creating NLT token mint
-        let nlt_mint_key = Keypair::new();
-        create_mint(&mut context, &nlt_mint_key, &payer_wallet.pubkey(), None, 0)
-            .await
-            .unwrap();
-
-        /*
-         * At this point we have the digital asset in the hands of (destination_owner / destination_token)
-         * which will act as the auction seller/creator
-         */
-
         let current_timestamp = context
             .banks_client
             .get_sysvar::<Clock>()
             .await
             .unwrap()
-            .unix_timestamp * MS_IN_SEC;
+            .unix_timestamp
+            * MS_IN_SEC;
 
-        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas_with_token_mint(
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
             &destination_owner.pubkey(),
             &da.mint.pubkey(),
-            &nlt_mint_key.pubkey(),
-            &current_timestamp
+            &current_timestamp,
         );
 
         let auction_duration = 300 * MS_IN_SEC;
-        let (_, tx) = boync_initialize_3(
+        let (_, tx) = boync_initialize_2(
             &mut context,
             &destination_owner,
             &da,
-            &nlt_mint_key.pubkey(),
             &auction,
             auction_bump,
             &treasury,
             &bidders_chest,
             &current_timestamp,
-            &destination_token,   // creator token
-            Some(&auction_duration)
+            &destination_token,     // creator token
+            Some(&auction_duration),
         );
 
         context.banks_client.process_transaction(tx).await.unwrap();
 
-        let user = Keypair::new();
-        airdrop(&mut context, &user.pubkey(), ONE_SOL)
-            .await
-            .unwrap();
+        let player1 = Keypair::new();
+        let keeper = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &keeper.pubkey(), ONE_SOL).await.unwrap();
 
+        /* Protocol takes 2.5% of the proceeds */
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
 
-        // Mint 10 NLT tokens
-        // user_token_account is account for NLT tokens
-        let user_token_account = Keypair::new();
-        create_token_account(
-            &mut context,
-            &user_token_account,
-            &nlt_mint_key.pubkey(),
-            &user.pubkey(),
-        )
-        .await.unwrap();
-        mint_tokens(
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Test assets carry a 5% royalty to a single verified creator, the context payer */
+        let creator = context.payer.pubkey();
+
+        let (bid_accounts, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Bid record is kept while the auction runs */
+        let (_, tx) = boync_close_bid_record(&mut context, &auction, &bid_accounts.bidder_state, &player1);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Keeper can't settle an ongoing auction */
+        let early_keeper = Keypair::new();
+        airdrop(&mut context, &early_keeper.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_settle(
             &mut context,
-            &nlt_mint_key.pubkey(),
-            &user_token_account.pubkey(),
-            10,
-            &payer_wallet.pubkey(),
-            Some(&payer_wallet),
-        )
-        .await.unwrap();
+            &da,
+            &auction,
+            &treasury,
+            &bidders_chest,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &player1.pubkey(),
+            &[creator],
+            &early_keeper,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
 
-        let mut ts = context
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let authority_balance = context
             .banks_client
-            .get_sysvar::<Clock>()
+            .get_balance(destination_owner.pubkey())
             .await
-            .unwrap()
-            .unix_timestamp * MS_IN_SEC;
-        ts = ts + MS_IN_SEC; // Add a second!
+            .unwrap();
+        let creator_balance = context.banks_client.get_balance(creator).await.unwrap();
+        let keeper_balance = context.banks_client.get_balance(keeper.pubkey()).await.unwrap();
 
-        let (accounts, tx) = boync_update_auction_bid_v3(
+        let (settle_accounts, tx) = boync_settle(
             &mut context,
+            &da,
             &auction,
+            &treasury,
             &bidders_chest,
-            &nlt_mint_key.pubkey(),
-            &user_token_account.pubkey(),
-            &user,
-            &ts);
-
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &player1.pubkey(),
+            &[creator],
+            &keeper,
+        );
         context.banks_client.process_transaction(tx).await.unwrap();
 
         /*
          Checks:
-           * 1 bid token has been debited from `user`
-           * 1 bid token has been credited to `bidders_chest`
-           * Update `auction` account
-           * Contents of User 1's `bidder_state` account
+           * Winner received token without signing
+           * Authority received the bidders_chest, less royalties and the protocol fee
+           * Creator received the royalty
+           * Fee recipient received the protocol fee
+           * Auction is settled
         */
 
-        let user_ta = Account::unpack_from_slice(
+        let player1_token = get_associated_token_address(&player1.pubkey(), &da.mint.pubkey());
+        let player1_token_account = Account::unpack_from_slice(
             context
                 .banks_client
-                .get_account(user_token_account.pubkey())
+                .get_account(player1_token)
                 .await
                 .unwrap()
                 .unwrap()
@@ -376,52 +415,54 @@ mod update_auction_claim {
                 .as_slice(),
         )
         .unwrap();
+        assert_eq!(player1_token_account.amount, 1);
 
-        /* User has 9 tokens */
-        assert_eq!(user_ta.amount, 9);
+        let settled_authority_balance = context
+            .banks_client
+            .get_balance(destination_owner.pubkey())
+            .await
+            .unwrap();
+        let treasury_rent = context.banks_client.get_rent().await.unwrap().minimum_balance(spl_token::state::Account::LEN);
+        assert!(settled_authority_balance >= authority_balance + 138_937_500 + treasury_rent);
 
-        let bidder = accounts.bidder;
-        let bidder_state = accounts.bidder_state;
+        let settled_creator_balance = context.banks_client.get_balance(creator).await.unwrap();
+        assert!(settled_creator_balance >= creator_balance + 7_500_000);
 
-        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
-        assert_eq!(bidder, auction_house_data.last_bidder);
+        let fee_recipient_balance = context.banks_client.get_balance(fee_recipient.pubkey()).await.unwrap();
+        assert!(fee_recipient_balance >= ONE_SOL + 3_562_500);
 
-        let auction_end_time = current_timestamp + auction_duration;
-        assert_eq!(
-            auction_end_time + (60 * 1000),
-            auction_house_data.end_auction_at
-        );
-        assert_eq!(auction_house_data.current_bid, ONE_SOL / 100);
+        /* No crank fee was set, the keeper only paid for the winner's token accounts */
+        let winner_accounts_rent = context.banks_client.get_balance(settle_accounts.recipient_token_account).await.unwrap()
+            + context.banks_client.get_balance(settle_accounts.destination_token_record).await.unwrap();
+        let settled_keeper_balance = context.banks_client.get_balance(keeper.pubkey()).await.unwrap();
+        assert!(settled_keeper_balance + winner_accounts_rent < keeper_balance);
 
-        let bidders_chest_ta = Account::unpack_from_slice(
-            context
-                .banks_client
-                .get_account(bidders_chest)
-                .await
-                .unwrap()
-                .unwrap()
-                .data
-                .as_slice(),
-        )
-        .unwrap();
+        /* Treasury and state were closed back to the authority */
+        assert!(context.banks_client.get_account(treasury).await.unwrap().is_none());
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
 
-        /* Bidders chest now has 1 token */
-        assert_eq!(bidders_chest_ta.amount, 1);
+        /* Winner reclaims the rent of the bid record, the earlier failed attempt used the same blockhash */
+        context.get_new_latest_blockhash().await.unwrap();
+        let player1_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
 
-        let bidder_state_data = boync_get_bidder_state_data(&mut context, &bidder_state).await;
-        assert_eq!(bidder, bidder_state_data.bidder);
-        assert_eq!(auction, bidder_state_data.auction);
-        assert_eq!(auction_house_data.current_bid, bidder_state_data.bid_value);
-        assert_eq!(ts, bidder_state_data.ts);
+        let (_, tx) = boync_close_bid_record(&mut context, &auction, &bid_accounts.bidder_state, &player1);
+        context.banks_client.process_transaction(tx).await.unwrap();
 
-        /* - User successfully placed a bid - */
+        assert!(context.banks_client.get_account(bid_accounts.bidder_state).await.unwrap().is_none());
+        assert!(context.banks_client.get_balance(player1.pubkey()).await.unwrap() > player1_balance);
+    }
 
-        /* - Warp blockchain forward */
-        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+    #[tokio::test]
+    async fn boync_keeper_settle_collects_crank_fee() {
+        let mut context = program_test().start_with_context().await;
 
-        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
 
-        let warped_ts = context
+        let current_timestamp = context
             .banks_client
             .get_sysvar::<Clock>()
             .await
@@ -429,55 +470,1868 @@ mod update_auction_claim {
             .unix_timestamp
             * MS_IN_SEC;
 
-        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
-        assert!(warped_ts > auction_house_data.end_auction_at);
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
 
-        // user_winning_token_account is account for the won NFT.
-        let user_winning_token_account = get_associated_token_address(&user.pubkey(), &da.mint.pubkey());
+        /* Crank fee is capped */
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_2_with(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+            Auction2Options {
+                crank_fee_bps: Some(MAX_CRANK_FEE_BPS + 1),
+                ..Auction2Options::default()
+            },
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidCrankFee);
 
-        /* By this point, the auction is ended, we can safely claim, and only 1 user bid -> user is
-         * winner. */
-        let (_claim_accounts, tx) = boync_update_auction_claim_v3(
+        let (_, tx) = boync_initialize_2_with(
             &mut context,
+            &destination_owner,
             &da,
             &auction,
+            auction_bump,
             &treasury,
-            &user_winning_token_account,
-            &user,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+            Auction2Options {
+                crank_fee_bps: Some(MAX_CRANK_FEE_BPS),
+                ..Auction2Options::default()
+            },
         );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let keeper = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &keeper.pubkey(), ONE_SOL).await.unwrap();
+
+        /* Protocol takes 2.5% of the proceeds */
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
 
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
         context.banks_client.process_transaction(tx).await.unwrap();
 
-        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
-        assert_eq!(auction_house_data.claimed, 1);
+        let creator = context.payer.pubkey();
 
-        let user1_winning_token_account = Account::unpack_from_slice(
-            context
-                .banks_client
-                .get_account(user_winning_token_account)
-                .await
-                .unwrap()
-                .unwrap()
-                .data
-                .as_slice(),
-        )
-        .unwrap();
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
 
-        /* Winner received token */
-        assert_eq!(user1_winning_token_account.amount, 1);
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
 
-        /* Check auction state account now hols 0.01 SOL that the user paid as the final price of
-         * that auction.
-         *
-         * We have some leftover from account creation so we assert > 0.01 SOL / < 0.02 SOL
-         * */
-        let ah_balance = context
-               .banks_client
-               .get_balance(auction)
-               .await
-               .unwrap();
-        assert!(ah_balance > ONE_SOL / 100);
-        assert!(ah_balance < 2 * ONE_SOL / 100);
+        let authority_balance = context
+            .banks_client
+            .get_balance(destination_owner.pubkey())
+            .await
+            .unwrap();
+        let creator_balance = context.banks_client.get_balance(creator).await.unwrap();
+        let keeper_balance = context.banks_client.get_balance(keeper.pubkey()).await.unwrap();
 
+        /* A third party settles */
+        let (settle_accounts, tx) = boync_settle(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &bidders_chest,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &player1.pubkey(),
+            &[creator],
+            &keeper,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks, out of the 0.15 SOL bidders_chest:
+           * Keeper received the 1% crank fee
+           * Creator received the 5% royalty
+           * Fee recipient received 2.5% of what is left after the crank fee and royalties
+           * Authority received the rest
+        */
+
+        let crank_fee = 1_500_000;
+        let tx_fee_budget = 10_000;
+        let winner_accounts_rent = context.banks_client.get_balance(settle_accounts.recipient_token_account).await.unwrap()
+            + context.banks_client.get_balance(settle_accounts.destination_token_record).await.unwrap();
+        let settled_keeper_balance = context.banks_client.get_balance(keeper.pubkey()).await.unwrap();
+        assert!(settled_keeper_balance + winner_accounts_rent <= keeper_balance + crank_fee);
+        assert!(settled_keeper_balance + winner_accounts_rent + tx_fee_budget > keeper_balance + crank_fee);
+
+        let settled_creator_balance = context.banks_client.get_balance(creator).await.unwrap();
+        assert!(settled_creator_balance >= creator_balance + 7_500_000);
+
+        let fee_recipient_balance = context.banks_client.get_balance(fee_recipient.pubkey()).await.unwrap();
+        assert_eq!(fee_recipient_balance, ONE_SOL + 3_525_000);
+
+        let settled_authority_balance = context
+            .banks_client
+            .get_balance(destination_owner.pubkey())
+            .await
+            .unwrap();
+        let treasury_rent = context.banks_client.get_rent().await.unwrap().minimum_balance(spl_token::state::Account::LEN);
+        assert!(settled_authority_balance >= authority_balance + 137_475_000 + treasury_rent);
+
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_keeper_settle_pays_seller_splits() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
+
+        /* Seller proceeds go 60/40 to two co-owners, none to the authority */
+        let co_owner1 = Keypair::new();
+        let co_owner2 = Keypair::new();
+        let mut seller_splits = SellerSplits::default();
+        seller_splits.splits[0] = SellerSplit { recipient: co_owner1.pubkey(), bps: 6_000 };
+        seller_splits.splits[1] = SellerSplit { recipient: co_owner2.pubkey(), bps: 4_000 };
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_2_with(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+            Auction2Options {
+                seller_splits: Some(seller_splits),
+                ..Auction2Options::default()
+            },
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let keeper = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &keeper.pubkey(), ONE_SOL).await.unwrap();
+
+        /* Protocol takes 2.5% of the proceeds */
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        /* Split recipients are passed after the creators */
+        let (_, tx) = boync_settle(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &bidders_chest,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &player1.pubkey(),
+            &[creator],
+            &keeper,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidSellerSplits);
+
+        let authority_balance = context
+            .banks_client
+            .get_balance(destination_owner.pubkey())
+            .await
+            .unwrap();
+
+        let (_, tx) = boync_settle(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &bidders_chest,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &player1.pubkey(),
+            &[creator, co_owner1.pubkey(), co_owner2.pubkey()],
+            &keeper,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks, out of the 138_937_500 seller proceeds:
+           * First co-owner received 60%
+           * Second co-owner received the remaining 40%
+           * Authority only got the treasury and state rent back
+        */
+
+        let co_owner1_balance = context.banks_client.get_balance(co_owner1.pubkey()).await.unwrap();
+        assert_eq!(co_owner1_balance, 83_362_500);
+
+        let co_owner2_balance = context.banks_client.get_balance(co_owner2.pubkey()).await.unwrap();
+        assert_eq!(co_owner2_balance, 55_575_000);
+
+        let fee_recipient_balance = context.banks_client.get_balance(fee_recipient.pubkey()).await.unwrap();
+        assert_eq!(fee_recipient_balance, ONE_SOL + 3_562_500);
+
+        let settled_authority_balance = context
+            .banks_client
+            .get_balance(destination_owner.pubkey())
+            .await
+            .unwrap();
+        assert!(settled_authority_balance < authority_balance + 83_362_500);
+
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_keeper_settle_unsold_v3() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        // Creating NLT token mint
+        let nlt_mint_key = Keypair::new();
+        create_mint(&mut context, &nlt_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas_with_token_mint(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &nlt_mint_key.pubkey(),
+            &current_timestamp,
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_3(
+            &mut context,
+            &destination_owner,
+            &da,
+            &nlt_mint_key.pubkey(),
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+            None,
+            None,
+            None
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let keeper = Keypair::new();
+        airdrop(&mut context, &keeper.pubkey(), ONE_SOL).await.unwrap();
+
+        /* Keeper can't settle an ongoing auction */
+        let (_, tx) = boync_settle3(&mut context, &da, &auction, &treasury, &destination_owner.pubkey(), &keeper);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let (settle_accounts, tx) = boync_settle3(&mut context, &da, &auction, &treasury, &destination_owner.pubkey(), &keeper);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Authority got the token back without signing
+           * Treasury and state were closed
+        */
+
+        let authority_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(settle_accounts.authority_token_account)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(authority_token_account.amount, 1);
+
+        assert!(context.banks_client.get_account(treasury).await.unwrap().is_none());
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_end_pays_proceeds_once() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        let (_, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Authority can't drain the bidders_chest mid-auction */
+        let (_, tx) = boync_end(
+            &mut context,
+            &da,
+            &auction,
+            &current_timestamp,
+            &fee_recipient.pubkey(),
+            &[creator],
+            &destination_owner,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let (_, tx) = boync_end(
+            &mut context,
+            &da,
+            &auction,
+            &current_timestamp,
+            &fee_recipient.pubkey(),
+            &[creator],
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* 150_000_000 bid: 5% royalty, then 2.5% protocol fee on the rest */
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        let proceeds = auction_house_data.proceeds.unwrap();
+        assert_eq!(proceeds.royalties, 7_500_000);
+        assert_eq!(proceeds.protocol_fee, 3_562_500);
+        assert_eq!(proceeds.seller, 138_937_500);
+        assert_eq!(proceeds.crank_fee, 0);
+
+        let bidders_chest_balance = context.banks_client.get_balance(bidders_chest).await.unwrap();
+        assert_eq!(bidders_chest_balance, 0);
+
+        /* Proceeds are paid only once */
+        context.get_new_latest_blockhash().await.unwrap();
+        let (_, tx) = boync_end(
+            &mut context,
+            &da,
+            &auction,
+            &current_timestamp,
+            &fee_recipient.pubkey(),
+            &[creator],
+            &destination_owner,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* State is kept until the token is claimed, then closed by the claim */
+        let player1_token = get_associated_token_address(&player1.pubkey(), &da.mint.pubkey());
+        let (_, tx) = boync_update_auction_claim(
+            &mut context,
+            &da,
+            &auction,
+            &bidders_chest,
+            &treasury,
+            &destination_owner.pubkey(),
+            &player1_token,
+            &player1,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_reserve_not_met_refunds_bidders() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_2_with(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+            Auction2Options {
+                reserve_price: Some(10 * ONE_SOL),
+                ..Auction2Options::default()
+            },
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        let player2 = Keypair::new();
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player2.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let creator = context.payer.pubkey();
+
+        let (player1_bid, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player1, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+        let (player2_bid, tx) = boync_place_bid2(&mut context, &auction, &bidders_chest, &player2, &1);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert_eq!(auction_house_data.reserve_price, Some(10 * ONE_SOL));
+        assert!(!auction_house_data.reserve_met());
+
+        /* Bids can't be refunded mid-auction */
+        let (_, tx) = boync_refund_bid(
+            &mut context,
+            &auction,
+            &bidders_chest,
+            &player1_bid.bidder_state,
+            &destination_owner.pubkey(),
+            &player1,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        /* Proceeds are never paid out below the reserve */
+        let (_, tx) = boync_end(
+            &mut context,
+            &da,
+            &auction,
+            &current_timestamp,
+            &fee_recipient.pubkey(),
+            &[creator],
+            &destination_owner,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::ReserveNotMet);
+
+        /* The highest bidder didn't win */
+        let player2_token = get_associated_token_address(&player2.pubkey(), &da.mint.pubkey());
+        let (_, tx) = boync_update_auction_claim(
+            &mut context,
+            &da,
+            &auction,
+            &bidders_chest,
+            &treasury,
+            &destination_owner.pubkey(),
+            &player2_token,
+            &player2,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::YouAreNotTheAuthority);
+
+        /* Every bidder gets their bid back */
+        let player1_bid_data = boync_get_bidder_state_data(&mut context, &player1_bid.bidder_state).await;
+        let player1_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+        let player1_bid_rent = context.banks_client.get_balance(player1_bid.bidder_state).await.unwrap();
+        let (_, tx) = boync_refund_bid(
+            &mut context,
+            &auction,
+            &bidders_chest,
+            &player1_bid.bidder_state,
+            &destination_owner.pubkey(),
+            &player1,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let fee = 5000;
+        assert_eq!(
+            context.banks_client.get_balance(player1.pubkey()).await.unwrap(),
+            player1_balance + player1_bid_data.bid_value + player1_bid_rent - fee
+        );
+        assert!(context.banks_client.get_account(player1_bid.bidder_state).await.unwrap().is_none());
+
+        /* A bid record can only be refunded once */
+        context.get_new_latest_blockhash().await.unwrap();
+        let (_, tx) = boync_refund_bid(
+            &mut context,
+            &auction,
+            &bidders_chest,
+            &player1_bid.bidder_state,
+            &destination_owner.pubkey(),
+            &player1,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* The authority takes the token back */
+        let (_, tx) = boync_update_auction_claim(
+            &mut context,
+            &da,
+            &auction,
+            &bidders_chest,
+            &treasury,
+            &destination_owner.pubkey(),
+            &destination_token,
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let destination_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(destination_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(destination_token_account.amount, 1);
+
+        /* Bids stay refundable after the token is reclaimed */
+        let player2_balance = context.banks_client.get_balance(player2.pubkey()).await.unwrap();
+        let (_, tx) = boync_refund_bid(
+            &mut context,
+            &auction,
+            &bidders_chest,
+            &player2_bid.bidder_state,
+            &destination_owner.pubkey(),
+            &player2,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+        assert!(context.banks_client.get_balance(player2.pubkey()).await.unwrap() > player2_balance);
+
+        /* Last refund empties the chest, the state is closed back to the authority */
+        assert_eq!(context.banks_client.get_balance(bidders_chest).await.unwrap(), 0);
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_user_claim_v3() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), 10_000_000_000)
+            .await
+            .unwrap();
+
+        // Creating NLT token mint
+        let nlt_mint_key = Keypair::new();
+        create_mint(&mut context, &nlt_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        /*
+         * At this point we have the digital asset in the hands of (destination_owner / destination_token)
+         * which will act as the auction seller/creator
+         */
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas_with_token_mint(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &nlt_mint_key.pubkey(),
+            &current_timestamp
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_3(
+            &mut context,
+            &destination_owner,
+            &da,
+            &nlt_mint_key.pubkey(),
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,   // creator token
+            Some(&auction_duration),
+            None,
+            None,
+            None
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let user = Keypair::new();
+        airdrop(&mut context, &user.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+
+        // Mint 10 NLT tokens
+        // user_token_account is account for NLT tokens
+        let user_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &user_token_account,
+            &nlt_mint_key.pubkey(),
+            &user.pubkey(),
+        )
+        .await.unwrap();
+        mint_tokens(
+            &mut context,
+            &nlt_mint_key.pubkey(),
+            &user_token_account.pubkey(),
+            10,
+            &payer_wallet.pubkey(),
+            Some(&payer_wallet),
+        )
+        .await.unwrap();
+
+        let mut ts = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+        ts = ts + MS_IN_SEC; // Add a second!
+
+        let (accounts, tx) = boync_update_auction_bid_v3(
+            &mut context,
+            &auction,
+            &bidders_chest,
+            &nlt_mint_key.pubkey(),
+            &user_token_account.pubkey(),
+            &user,
+            &ts);
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * 1 bid token has been debited from `user`
+           * 1 bid token has been credited to `bidders_chest`
+           * Update `auction` account
+           * Contents of User 1's `bidder_state` account
+        */
+
+        let user_ta = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(user_token_account.pubkey())
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+
+        /* User has 9 tokens */
+        assert_eq!(user_ta.amount, 9);
+
+        let bidder = accounts.bidder;
+        let bidder_state = accounts.bidder_state;
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert_eq!(bidder, auction_house_data.last_bidder);
+
+        let auction_end_time = current_timestamp + auction_duration;
+        assert_eq!(
+            auction_end_time + (60 * 1000),
+            auction_house_data.end_auction_at
+        );
+        assert_eq!(auction_house_data.current_bid, ONE_SOL / 100);
+
+        let bidders_chest_ta = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(bidders_chest)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+
+        /* Bidders chest now has 1 token */
+        assert_eq!(bidders_chest_ta.amount, 1);
+
+        let bidder_state_data = boync_get_bidder_state_data(&mut context, &bidder_state).await;
+        assert_eq!(bidder, bidder_state_data.bidder);
+        assert_eq!(auction, bidder_state_data.auction);
+        assert_eq!(auction_house_data.current_bid, bidder_state_data.bid_value);
+        // Stored timestamp comes from the clock, not the client supplied `ts`
+        assert!(bidder_state_data.ts >= ts - MS_IN_SEC && bidder_state_data.ts < ts);
+
+        /* - User successfully placed a bid - */
+
+        /* - Warp blockchain forward */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let warped_ts = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert!(warped_ts > auction_house_data.end_auction_at);
+
+        // user_winning_token_account is account for the won NFT.
+        let user_winning_token_account = get_associated_token_address(&user.pubkey(), &da.mint.pubkey());
+
+        /* By this point, the auction is ended, we can safely claim, and only 1 user bid -> user is
+         * winner. */
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let (_claim_accounts, tx) = boync_update_auction_claim_v3(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &user_winning_token_account,
+            &[context.payer.pubkey()],
+            &user,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert_eq!(auction_house_data.claimed, 1);
+
+        let user1_winning_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(user_winning_token_account)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+
+        /* Winner received token */
+        assert_eq!(user1_winning_token_account.amount, 1);
+
+        /* Check auction state account now hols the 0.01 SOL that the user paid as the final price of
+         * that auction, less the 5% creator royalty and the 2.5% protocol fee.
+         *
+         * We have some leftover from account creation so we assert > 0.00926 SOL / < 0.02 SOL
+         * */
+        let ah_balance = context
+               .banks_client
+               .get_balance(auction)
+               .await
+               .unwrap();
+        assert!(ah_balance > ONE_SOL / 100 * 95 / 100 * 975 / 1000);
+        assert!(ah_balance < 2 * ONE_SOL / 100);
+
+        /* Protocol fee (2.5%) went straight to the fee recipient */
+        let fee_recipient_balance = context.banks_client.get_balance(fee_recipient.pubkey()).await.unwrap();
+        assert!(fee_recipient_balance > ONE_SOL);
+
+        /* Authority withdraws the bid tokens, then the proceeds */
+        let authority_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &authority_token_account,
+            &nlt_mint_key.pubkey(),
+            &destination_owner.pubkey(),
+        )
+        .await.unwrap();
+
+        let (_, tx) = boync_withdraw_chest3(
+            &mut context,
+            &auction,
+            &bidders_chest,
+            &authority_token_account.pubkey(),
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let authority_ta = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(authority_token_account.pubkey())
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(authority_ta.amount, 1);
+        assert!(context.banks_client.get_account(bidders_chest).await.unwrap().is_none());
+
+        /* Bidder's deposit is refunded, the state can't close while it's held */
+        let (refund_accounts, tx) = boync_refund_deposit3(&mut context, &auction, &user.pubkey(), &destination_owner);
+        context.banks_client.process_transaction(tx).await.unwrap();
+        assert!(context.banks_client.get_account(refund_accounts.deposit).await.unwrap().is_none());
+
+        let authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+
+        let (_, tx) = boync_withdraw_proceeds3(&mut context, &auction, &bidders_chest, &destination_owner);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Authority got the final price net of royalties and fees and the state rent, minus the transaction fee */
+        let withdrawn_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        assert!(withdrawn_balance > authority_balance + ONE_SOL / 100 * 95 / 100 * 975 / 1000 - 10_000);
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_runner_up_claims_after_default_v3() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        // Creating NLT token mint
+        let nlt_mint_key = Keypair::new();
+        create_mint(&mut context, &nlt_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas_with_token_mint(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &nlt_mint_key.pubkey(),
+            &current_timestamp
+        );
+
+        /* Winner has one minute after the end to pay, bidders lock 0.1 SOL */
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_3(
+            &mut context,
+            &destination_owner,
+            &da,
+            &nlt_mint_key.pubkey(),
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,   // creator token
+            Some(&auction_duration),
+            Some(60 * MS_IN_SEC),
+            Some(ONE_SOL / 10),
+            None
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let mut bidders = vec![];
+        for _ in 0..2 {
+            let user = Keypair::new();
+            airdrop(&mut context, &user.pubkey(), ONE_SOL)
+                .await
+                .unwrap();
+
+            let user_token_account = Keypair::new();
+            create_token_account(
+                &mut context,
+                &user_token_account,
+                &nlt_mint_key.pubkey(),
+                &user.pubkey(),
+            )
+            .await.unwrap();
+            mint_tokens(
+                &mut context,
+                &nlt_mint_key.pubkey(),
+                &user_token_account.pubkey(),
+                10,
+                &payer_wallet.pubkey(),
+                Some(&payer_wallet),
+            )
+            .await.unwrap();
+
+            bidders.push((user, user_token_account));
+        }
+
+        let ts = current_timestamp + MS_IN_SEC;
+        for (idx, (user, user_token_account)) in bidders.iter().enumerate() {
+            let bid_ts = ts + idx as i64 * MS_IN_SEC;
+            let (_, tx) = boync_update_auction_bid_v3(
+                &mut context,
+                &auction,
+                &bidders_chest,
+                &nlt_mint_key.pubkey(),
+                &user_token_account.pubkey(),
+                user,
+                &bid_ts);
+
+            context.banks_client.process_transaction(tx).await.unwrap();
+        }
+
+        let (runner_up, _) = &bidders[0];
+        let (winner, _) = &bidders[1];
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, winner.pubkey());
+        assert_eq!(auction_house_data.runner_up, runner_up.pubkey());
+        let runner_up_bid = auction_house_data.runner_up_bid;
+        assert!(runner_up_bid < auction_house_data.current_bid);
+
+        /* - Warp past the end of the auction and the payment window */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Winner missed the payment window */
+        let winner_token_account = get_associated_token_address(&winner.pubkey(), &da.mint.pubkey());
+        let (_, tx) = boync_update_auction_claim_v3(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &winner_token_account,
+            &[context.payer.pubkey()],
+            winner,
+        );
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        /* Deposits stay locked until the auction is settled */
+        let (_, tx) = boync_refund_deposit3(&mut context, &auction, &runner_up.pubkey(), &destination_owner);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        let authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+
+        /* Seller offers the token to the runner-up at their last price */
+        let (offer_accounts, tx) = boync_offer_runner_up3(&mut context, &auction, &winner.pubkey(), &destination_owner);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Winner's deposit is slashed to the seller */
+        assert!(context.banks_client.get_account(offer_accounts.defaulter_deposit).await.unwrap().is_none());
+        let slashed_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        assert!(slashed_balance > authority_balance + ONE_SOL / 10 - 10_000);
+
+        let defaulter_data = boync_get_defaulter_data(&mut context, &winner.pubkey()).await;
+        assert_eq!(defaulter_data.bidder, winner.pubkey());
+        assert_eq!(defaulter_data.default_count, 1);
+        assert_eq!(defaulter_data.last_auction, auction);
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, runner_up.pubkey());
+        assert_eq!(auction_house_data.current_bid, runner_up_bid);
+        assert!(auction_house_data.payment_due_at > 0);
+
+        /* Runner-up pays within the new window and gets the token */
+        let runner_up_token_account = get_associated_token_address(&runner_up.pubkey(), &da.mint.pubkey());
+        let (_, tx) = boync_update_auction_claim_v3(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &runner_up_token_account,
+            &[context.payer.pubkey()],
+            runner_up,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let runner_up_ta = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(runner_up_token_account)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(runner_up_ta.amount, 1);
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert_eq!(auction_house_data.claimed, 1);
+
+        /* Runner-up's deposit is refunded after settlement */
+        let runner_up_balance = context.banks_client.get_balance(runner_up.pubkey()).await.unwrap();
+
+        let (_, tx) = boync_refund_deposit3(&mut context, &auction, &runner_up.pubkey(), &destination_owner);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let refunded_balance = context.banks_client.get_balance(runner_up.pubkey()).await.unwrap();
+        assert!(refunded_balance > runner_up_balance + ONE_SOL / 10);
+    }
+
+    #[tokio::test]
+    async fn boync_defaulter_cannot_refund_after_reclaim_v3() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        // Creating NLT token mint
+        let nlt_mint_key = Keypair::new();
+        create_mint(&mut context, &nlt_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas_with_token_mint(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &nlt_mint_key.pubkey(),
+            &current_timestamp
+        );
+
+        /* Winner has one minute after the end to pay, bidders lock 0.1 SOL */
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_3(
+            &mut context,
+            &destination_owner,
+            &da,
+            &nlt_mint_key.pubkey(),
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,   // creator token
+            Some(&auction_duration),
+            Some(60 * MS_IN_SEC),
+            Some(ONE_SOL / 10),
+            None
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let winner = Keypair::new();
+        airdrop(&mut context, &winner.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        let winner_bid_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &winner_bid_token_account,
+            &nlt_mint_key.pubkey(),
+            &winner.pubkey(),
+        )
+        .await.unwrap();
+        mint_tokens(
+            &mut context,
+            &nlt_mint_key.pubkey(),
+            &winner_bid_token_account.pubkey(),
+            10,
+            &payer_wallet.pubkey(),
+            Some(&payer_wallet),
+        )
+        .await.unwrap();
+
+        let ts = current_timestamp + MS_IN_SEC;
+        let (_, tx) = boync_update_auction_bid_v3(
+            &mut context,
+            &auction,
+            &bidders_chest,
+            &nlt_mint_key.pubkey(),
+            &winner_bid_token_account.pubkey(),
+            &winner,
+            &ts);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* - Warp past the end of the auction and the payment window */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        let authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+
+        /* Seller takes the token back from the winner that didn't pay */
+        let (reclaim_accounts, tx) = boync_reclaim_defaulted3(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &winner.pubkey(),
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Winner's deposit is slashed to the seller */
+        assert!(context.banks_client.get_account(reclaim_accounts.defaulter_deposit).await.unwrap().is_none());
+        let slashed_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        assert!(slashed_balance > authority_balance + ONE_SOL / 10 - ONE_SOL / 100);
+
+        let auction_house_data = boync_get_auction_data_v3(&mut context, &auction).await;
+        assert_eq!(auction_house_data.claimed, 1);
+        assert_eq!(auction_house_data.open_deposits, 0);
+
+        /* Defaulter has no deposit left to refund */
+        let (_, tx) = boync_refund_deposit3(&mut context, &auction, &winner.pubkey(), &destination_owner);
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn boync_authority_cancel() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* No bids yet, the authority can cancel */
+        let (_cancel_accounts, tx) = boync_cancel_auction(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_token,
+            &destination_owner,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let destination_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(destination_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+
+        /* Authority got the token back */
+        assert_eq!(destination_token_account.amount, 1);
+
+        /* State and treasury are closed */
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+        assert!(context.banks_client.get_account(treasury).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_authority_cancel_v3_empties_chest() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let payer_wallet = Keypair::new();
+        airdrop(&mut context, &payer_wallet.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        // Creating NLT token mint
+        let nlt_mint_key = Keypair::new();
+        create_mint(&mut context, &nlt_mint_key, &payer_wallet.pubkey(), None, 0)
+            .await
+            .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, chest) = find_boync_auction_pdas_with_token_mint(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &nlt_mint_key.pubkey(),
+            &current_timestamp,
+        );
+
+        let (_, tx) = boync_initialize_3(
+            &mut context,
+            &destination_owner,
+            &da,
+            &nlt_mint_key.pubkey(),
+            &auction,
+            auction_bump,
+            &treasury,
+            &chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            None,
+            None,
+            None,
+            None
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Bid tokens sent straight to the chest, without a bid */
+        mint_tokens(&mut context, &nlt_mint_key.pubkey(), &chest, 5, &payer_wallet.pubkey(), Some(&payer_wallet))
+            .await
+            .unwrap();
+
+        /* A non-empty chest needs somewhere to go */
+        let (_, tx) = boync_cancel_auction3(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &chest,
+            &destination_token,
+            None,
+            &destination_owner,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::ChestTokenAccountMissing);
+
+        let authority_nlt_token = Keypair::new();
+        create_token_account(&mut context, &authority_nlt_token, &nlt_mint_key.pubkey(), &destination_owner.pubkey())
+            .await
+            .unwrap();
+
+        let (_, tx) = boync_cancel_auction3(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &chest,
+            &destination_token,
+            Some(&authority_nlt_token.pubkey()),
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let destination_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(destination_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+
+        /* Authority got the token and the stray bid tokens back */
+        assert_eq!(destination_token_account.amount, 1);
+
+        let authority_nlt_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(authority_nlt_token.pubkey())
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(authority_nlt_token_account.amount, 5);
+
+        /* State, treasury and chest are closed */
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+        assert!(context.banks_client.get_account(treasury).await.unwrap().is_none());
+        assert!(context.banks_client.get_account(chest).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_authority_cancel_after_bid_fails() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
+
+        let auction_duration = 300 * MS_IN_SEC;
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,     // creator token
+            Some(&auction_duration),
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let player1 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL)
+            .await
+            .unwrap();
+
+        let ts = current_timestamp + MS_IN_SEC;
+        let (_, tx) =
+            boync_update_auction_bid(&mut context, &auction, &bidders_chest, &player1, &ts);
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let (_cancel_accounts, tx) = boync_cancel_auction(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_token,
+            &destination_owner,
+        );
+
+        /* Auction has a bid, it can't be cancelled anymore */
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert_eq!(auction_house_data.last_bidder, player1.pubkey());
+    }
+
+    #[tokio::test]
+    async fn boync_authority_reclaims_unsold_dutch() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
+
+        let (_, tx) = boync_initialize_dutch(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL,                // start price
+            ONE_SOL / 10,           // floor price
+            300 * MS_IN_SEC,
+            None,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        let player1 = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player1.pubkey(), 2 * ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Authority can't reclaim while the auction runs */
+        let (_, tx) = boync_claim_dutch(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &destination_owner,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionActive);
+
+        /* Warp blockchain forward, past the end of the auction */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+        context.get_new_latest_blockhash().await.unwrap();
+
+        /* Unsold item can't be bought after the end */
+        let (_, tx) = boync_buy_dutch(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[],
+            &[context.payer.pubkey()],
+            &player1,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionEnded);
+
+        /* Only the authority reclaims an unsold item */
+        let (_, tx) = boync_claim_dutch(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &player1,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::YouAreNotTheAuthority);
+
+        let (_, tx) = boync_claim_dutch(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &destination_owner,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Authority got the token back
+           * Treasury was closed back to the authority
+           * Auction is settled unsold and closed back to the authority
+        */
+        let authority_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(destination_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(authority_token_account.amount, 1);
+
+        assert!(context.banks_client.get_account(treasury).await.unwrap().is_none());
+
+        assert!(context.banks_client.get_account(auction).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn boync_sealed_winner_pays_second_price() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+            * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp,
+        );
+
+        /* 5 minutes to commit, then 15 minutes to reveal */
+        let (_, tx) = boync_initialize_sealed(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // starting price
+            ONE_SOL / 10,           // min deposit
+            1000,                   // 10% of unrevealed deposits are forfeited
+            300 * MS_IN_SEC,
+            900 * MS_IN_SEC,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let admin = program_upgrade_authority();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &fee_recipient.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Test assets carry a 5% royalty to a single verified creator, the context payer */
+        let creator = context.payer.pubkey();
+
+        let player1 = Keypair::new();
+        let player2 = Keypair::new();
+        let player3 = Keypair::new();
+        airdrop(&mut context, &player1.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player2.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &player3.pubkey(), ONE_SOL).await.unwrap();
+
+        /* Player 1 bids 0.5 SOL, player 2 bids 0.3 SOL, player 3 never reveals */
+        let salt = [7u8; 32];
+        for (player, amount) in [
+            (&player1, 5 * ONE_SOL / 10),
+            (&player2, 3 * ONE_SOL / 10),
+            (&player3, 4 * ONE_SOL / 10),
+        ] {
+            let (_, tx) = boync_commit_bid(
+                &mut context,
+                &auction,
+                player,
+                sealed_bid_commitment(amount, &salt),
+                2 * ONE_SOL / 10,
+            );
+            context.banks_client.process_transaction(tx).await.unwrap();
+        }
+
+        /* Warp blockchain forward, into the reveal window */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+
+        for (player, amount) in [(&player1, 5 * ONE_SOL / 10), (&player2, 3 * ONE_SOL / 10)] {
+            let (_, tx) = boync_reveal_bid(&mut context, &auction, player, amount, salt);
+            context.banks_client.process_transaction(tx).await.unwrap();
+        }
+
+        let auction_house_data = boync_get_auction_data_sealed(&mut context, &auction).await;
+        assert_eq!(auction_house_data.highest_bid, 5 * ONE_SOL / 10);
+        assert_eq!(auction_house_data.second_bid, 3 * ONE_SOL / 10);
+        assert_eq!(auction_house_data.last_bidder, player1.pubkey());
+        assert_eq!(auction_house_data.clearing_price(), 3 * ONE_SOL / 10);
+
+        /* Winner can't claim before the reveal window is over */
+        let (_, tx) = boync_claim_sealed(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[creator],
+            &player1,
+            true,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionActive);
+
+        /* Warp blockchain forward, past the reveal window */
+        let current_slot = context.banks_client.get_root_slot().await.unwrap();
+        context.warp_to_slot(current_slot + 260000).unwrap(); // 260000 slots -> 619 seconds
+        context.get_new_latest_blockhash().await.unwrap();
+
+        /* Winner's escrow is settled by claiming, not withdrawn */
+        let (_, tx) = boync_withdraw_sealed_bid(&mut context, &auction, &destination_owner.pubkey(), &player1);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::WinnerMustClaim);
+
+        /* Revealed losing bid is refunded in full, the unrevealed one forfeits 10% to the authority */
+        let authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        let player2_balance = context.banks_client.get_balance(player2.pubkey()).await.unwrap();
+        let player3_balance = context.banks_client.get_balance(player3.pubkey()).await.unwrap();
+
+        let (_, tx) = boync_withdraw_sealed_bid(&mut context, &auction, &destination_owner.pubkey(), &player2);
+        context.banks_client.process_transaction(tx).await.unwrap();
+        let (_, tx) = boync_withdraw_sealed_bid(&mut context, &auction, &destination_owner.pubkey(), &player3);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let withdrawn_player2_balance = context.banks_client.get_balance(player2.pubkey()).await.unwrap();
+        assert!(withdrawn_player2_balance >= player2_balance + 3 * ONE_SOL / 10 - 10_000);
+
+        let withdrawn_player3_balance = context.banks_client.get_balance(player3.pubkey()).await.unwrap();
+        assert!(withdrawn_player3_balance >= player3_balance + 18 * ONE_SOL / 100 - 10_000);
+        assert!(withdrawn_player3_balance < player3_balance + 2 * ONE_SOL / 10);
+
+        let forfeited_authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        assert_eq!(forfeited_authority_balance, authority_balance + 2 * ONE_SOL / 100);
+
+        assert!(context.banks_client.get_account(find_boync_sealed_bid_address(&auction, &player2.pubkey()).0).await.unwrap().is_none());
+
+        /* Winner pays the second highest bid, the rest of the escrow goes back to them */
+        let player1_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+
+        let (_, tx) = boync_claim_sealed(
+            &mut context,
+            &da,
+            &auction,
+            &treasury,
+            &destination_owner.pubkey(),
+            &fee_recipient.pubkey(),
+            &[creator],
+            &player1,
+            true,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /*
+         Checks:
+           * Winner received the token
+           * Authority received the clearing price, less royalties and the protocol fee
+           * Winner got the escrow above the clearing price back
+           * Auction is settled
+        */
+        let player1_token = get_associated_token_address(&player1.pubkey(), &da.mint.pubkey());
+        let player1_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(player1_token)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+        assert_eq!(player1_token_account.amount, 1);
+
+        let clearing_price = 3 * ONE_SOL / 10;
+        let royalties = clearing_price * 500 / 10_000;
+        let fee = (clearing_price - royalties) * 250 / 10_000;
+        let treasury_rent = context.banks_client.get_rent().await.unwrap().minimum_balance(spl_token::state::Account::LEN);
+        let settled_authority_balance = context.banks_client.get_balance(destination_owner.pubkey()).await.unwrap();
+        assert_eq!(
+            settled_authority_balance,
+            forfeited_authority_balance + clearing_price - royalties - fee + treasury_rent
+        );
+
+        /* Escrow above the clearing price came back, less the token account rent and fees */
+        let claimed_player1_balance = context.banks_client.get_balance(player1.pubkey()).await.unwrap();
+        assert!(claimed_player1_balance >= player1_balance + 2 * ONE_SOL / 10 - ONE_SOL / 100);
+
+        assert!(context.banks_client.get_account(find_boync_sealed_bid_address(&auction, &player1.pubkey()).0).await.unwrap().is_none());
+
+        let auction_house_data = boync_get_auction_data_sealed(&mut context, &auction).await;
+        assert_eq!(auction_house_data.claimed, 1);
+        assert!(auction_house_data.state == AuctionState::Settled);
     }
 }
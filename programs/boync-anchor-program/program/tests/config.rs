@@ -0,0 +1,77 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod utils;
+
+use solana_program_test::*;
+use solana_sdk::signature::{Keypair, Signer};
+
+use utils::*;
+
+mod config {
+
+    use boync_anchor_program::errors::AuctionError;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn only_upgrade_authority_initializes_config() {
+        let mut context = program_test().start_with_context().await;
+
+        let fee_recipient = Keypair::new();
+        let impostor = Keypair::new();
+        airdrop(&mut context, &impostor.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &impostor);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::NotUpgradeAuthority);
+
+        let admin = program_upgrade_authority();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let config = boync_get_config_data(&mut context).await;
+        assert_eq!(config.admin, admin.pubkey());
+        assert_eq!(config.fee_recipient, fee_recipient.pubkey());
+        assert_eq!(config.fee_bps, 250);
+    }
+
+    #[tokio::test]
+    async fn admin_rotates_config() {
+        let mut context = program_test().start_with_context().await;
+
+        let admin = program_upgrade_authority();
+        let new_admin = Keypair::new();
+        let fee_recipient = Keypair::new();
+        airdrop(&mut context, &admin.pubkey(), ONE_SOL).await.unwrap();
+        airdrop(&mut context, &new_admin.pubkey(), ONE_SOL).await.unwrap();
+
+        let (_, tx) = boync_initialize_config(&mut context, 250, &fee_recipient.pubkey(), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Only the admin updates the config */
+        let (_, tx) = boync_update_config(&mut context, 100, &fee_recipient.pubkey(), None, &new_admin);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidAuthority);
+
+        let (_, tx) = boync_update_config(&mut context, 100, &fee_recipient.pubkey(), Some(&new_admin.pubkey()), &admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let config = boync_get_config_data(&mut context).await;
+        assert_eq!(config.admin, new_admin.pubkey());
+        assert_eq!(config.fee_bps, 100);
+
+        /* Previous admin lost access */
+        let (_, tx) = boync_update_config(&mut context, 0, &fee_recipient.pubkey(), None, &admin);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidAuthority);
+
+        let (_, tx) = boync_update_config(&mut context, 0, &fee_recipient.pubkey(), None, &new_admin);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let config = boync_get_config_data(&mut context).await;
+        assert_eq!(config.admin, new_admin.pubkey());
+        assert_eq!(config.fee_bps, 0);
+    }
+}
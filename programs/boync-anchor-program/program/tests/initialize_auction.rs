@@ -20,7 +20,14 @@ mod standard_transfer {
     // use std::println;
 
     use anchor_lang::prelude::Pubkey;
-    use boync_anchor_program::account::BoyncAuction2;
+    use boync_anchor_program::account::{
+        AuctionState, BidIncrement, BoyncAuction2, BoyncAuction2Legacy, TimeExtensionPolicy,
+    };
+    use solana_sdk::{account::Account as SolanaAccount, rent::Rent};
+    use boync_anchor_program::constants::{
+        MAX_AUCTION_DURATION, MAX_START_IN_PAST, MIN_AUCTION_DURATION,
+    };
+    use boync_anchor_program::errors::AuctionError;
     use mpl_token_metadata::{instruction::TransferArgs, state::TokenStandard};
     use solana_program_test::ProgramTestContext;
     use spl_associated_token_account::get_associated_token_address;
@@ -201,6 +208,9 @@ mod standard_transfer {
             &bidders_chest,
             &current_timestamp,
             &destination_token,   // creator token
+            None,
+            None,
+            None,
             None
         );
 
@@ -230,6 +240,87 @@ mod standard_transfer {
         );
     }
 
+    #[tokio::test]
+    async fn boync_initialize_dutch_auction_programmable_non_fungible() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::ProgrammableNonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = current_timestamp(&mut context).await;
+
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        /* Floor can't be above the start price */
+        let (_, tx) = boync_initialize_dutch(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL / 10,           // start price
+            ONE_SOL,                // floor price
+            THIRTY_MINUTES_IN_MSEC,
+            None,
+        );
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidPriceRange);
+
+        let (_, tx) = boync_initialize_dutch(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &current_timestamp,
+            &destination_token,     // creator token
+            ONE_SOL,                // start price
+            ONE_SOL / 10,           // floor price
+            THIRTY_MINUTES_IN_MSEC,
+            None,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let treasury_token_account = Account::unpack_from_slice(
+            context
+                .banks_client
+                .get_account(treasury)
+                .await
+                .unwrap()
+                .unwrap()
+                .data
+                .as_slice(),
+        )
+        .unwrap();
+
+        assert_eq!(treasury_token_account.amount, 1);
+
+        let auction_house_data = boync_get_auction_data_dutch(&mut context, &auction).await;
+
+        assert_eq!(auction_house_data.authority, destination_owner.pubkey());
+        assert_eq!(auction_house_data.start_price, ONE_SOL);
+        assert_eq!(auction_house_data.floor_price, ONE_SOL / 10);
+        assert_eq!(auction_house_data.sold_price, 0);
+        assert_eq!(auction_house_data.claimed, 0);
+        assert!(auction_house_data.state == AuctionState::Created);
+        assert_eq!(auction_house_data.start_auction_at, current_timestamp);
+        assert_eq!(
+            auction_house_data.end_auction_at,
+            current_timestamp + THIRTY_MINUTES_IN_MSEC
+        );
+    }
+
      #[tokio::test]
      async fn boync_initialize_auction_2_non_fungible() {
         let mut context = program_test().start_with_context().await;
@@ -309,9 +400,392 @@ mod standard_transfer {
         );
      }
 
+    #[tokio::test]
+    async fn boync_update_auction_config_before_start() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::NonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        /* Auction starts in 10 minutes */
+        let start_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC + 10 * ONE_MINUTE_IN_MSEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &start_timestamp
+        );
+
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &start_timestamp,
+            &destination_token,       // creator token
+            None
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let new_start = start_timestamp + 5 * ONE_MINUTE_IN_MSEC;
+        let new_end = new_start + THIRTY_MINUTES_IN_MSEC;
+        let (_, tx) = boync_update_auction_config(
+            &mut context,
+            &auction,
+            &destination_owner,
+            4 * ONE_SOL,
+            new_start,
+            new_end,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+
+        assert_eq!(auction_house_data.start_auction_at, new_start);
+        assert_eq!(auction_house_data.end_auction_at, new_end);
+        assert_eq!(auction_house_data.starting_price, 200_000_000);
+        assert_eq!(auction_house_data.next_bid, 200_000_000);
+    }
+
+    #[tokio::test]
+    async fn boync_update_auction_config_after_start_fails() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::NonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let current_timestamp = context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &current_timestamp
+        );
+
+        let (_, tx) = boync_initialize_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &current_timestamp,
+            &destination_token,       // creator token
+            None
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let (_, tx) = boync_update_auction_config(
+            &mut context,
+            &auction,
+            &destination_owner,
+            4 * ONE_SOL,
+            current_timestamp + ONE_MINUTE_IN_MSEC,
+            current_timestamp + THIRTY_MINUTES_IN_MSEC,
+        );
+
+        /* Auction is already running */
+        assert!(context.banks_client.process_transaction(tx).await.is_err());
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert_eq!(auction_house_data.start_auction_at, current_timestamp);
+    }
+
+    /* Initializes a NonFungible v2 auction starting at `start_at` and returns the transaction result */
+    async fn initialize_auction_2_with_timing(
+        context: &mut ProgramTestContext,
+        start_at: i64,
+        duration: i64,
+    ) -> StdResult<(), BanksClientError> {
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(context, TokenStandard::NonFungible, 1)
+                .await
+                .unwrap();
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &start_at
+        );
+
+        let (_, tx) = boync_initialize_2(
+            context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &start_at,
+            &destination_token,       // creator token
+            Some(&duration)
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    async fn current_timestamp(context: &mut ProgramTestContext) -> i64 {
+        context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp * MS_IN_SEC
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_legacy_auction_2_non_fungible() {
+        let mut context = program_test().start_with_context().await;
+
+        let token_standard = TokenStandard::NonFungible;
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(&mut context, token_standard, 1)
+                .await
+                .unwrap();
+
+        let start_at = current_timestamp(&mut context).await;
+
+        let ((auction, auction_bump), treasury, bidders_chest) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &start_at
+        );
+
+        let (_, tx) = boync_initialize_legacy_2(
+            &mut context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &bidders_chest,
+            &start_at,
+            &destination_token,   // creator token
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        /* Legacy listings keep the 5% step and 60 second extension per bid */
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert_eq!(auction_house_data.starting_price, 150_000_000);
+        assert!(auction_house_data.bid_increment == BidIncrement::LEGACY_V2);
+        assert!(auction_house_data.time_extension == TimeExtensionPolicy::LEGACY);
+        assert!(auction_house_data.state == AuctionState::Created);
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_auction_2_end_before_start_fails() {
+        let mut context = program_test().start_with_context().await;
+        let now = current_timestamp(&mut context).await;
+
+        let err = initialize_auction_2_with_timing(&mut context, now, -ONE_MINUTE_IN_MSEC)
+            .await
+            .unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidAuctionWindow);
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_auction_2_too_short_fails() {
+        let mut context = program_test().start_with_context().await;
+        let now = current_timestamp(&mut context).await;
+
+        let err = initialize_auction_2_with_timing(&mut context, now, MIN_AUCTION_DURATION - 1)
+            .await
+            .unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionTooShort);
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_auction_2_too_long_fails() {
+        let mut context = program_test().start_with_context().await;
+        let now = current_timestamp(&mut context).await;
+
+        let err = initialize_auction_2_with_timing(&mut context, now, MAX_AUCTION_DURATION + 1)
+            .await
+            .unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionTooLong);
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_auction_2_start_in_past_fails() {
+        let mut context = program_test().start_with_context().await;
+        let now = current_timestamp(&mut context).await;
+
+        let err = initialize_auction_2_with_timing(
+            &mut context,
+            now - MAX_START_IN_PAST - ONE_MINUTE_IN_MSEC,
+            THIRTY_MINUTES_IN_MSEC
+        )
+            .await
+            .unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionStartInPast);
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_auction_2_seconds_timestamp_fails() {
+        let mut context = program_test().start_with_context().await;
+        let now_in_seconds = current_timestamp(&mut context).await / MS_IN_SEC;
+
+        let err = initialize_auction_2_with_timing(&mut context, now_in_seconds, 30 * 60)
+            .await
+            .unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidTimestamp);
+    }
+
+    /* Initializes an English auction with the given prices and returns the transaction result */
+    async fn initialize_english_with_prices(
+        context: &mut ProgramTestContext,
+        starting_price: u64,
+        min_bid_increment: u64,
+    ) -> StdResult<(), BanksClientError> {
+        let (da, destination_token, destination_owner) =
+            setup_transfer_token(context, TokenStandard::NonFungible, 1)
+                .await
+                .unwrap();
+
+        let start_at = current_timestamp(context).await;
+        let ((auction, auction_bump), treasury, _) = find_boync_auction_pdas(
+            &destination_owner.pubkey(),
+            &da.mint.pubkey(),
+            &start_at
+        );
+
+        let (_, tx) = boync_initialize_english(
+            context,
+            &destination_owner,
+            &da,
+            &auction,
+            auction_bump,
+            &treasury,
+            &start_at,
+            &destination_token,       // creator token
+            starting_price,
+            min_bid_increment,
+            None,
+            None,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_english_zero_starting_price_fails() {
+        let mut context = program_test().start_with_context().await;
+
+        let err = initialize_english_with_prices(&mut context, 0, ONE_SOL / 100)
+            .await
+            .unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidStartingPrice);
+    }
+
+    #[tokio::test]
+    async fn boync_initialize_english_zero_bid_increment_fails() {
+        let mut context = program_test().start_with_context().await;
+
+        let err = initialize_english_with_prices(&mut context, ONE_SOL / 10, 0)
+            .await
+            .unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidBidIncrement);
+    }
+
     // TODO: Write this test
     // #[tokio::test]
     // async fn boync_initialize_auction_2_programmable_non_fungible_with_auth() {
     // }
 
+    #[tokio::test]
+    async fn boync_migrate_legacy_auction_2() {
+        let authority = Keypair::new();
+        let last_bidder = Keypair::new();
+        let auction = Pubkey::new_unique();
+
+        let legacy = BoyncAuction2Legacy {
+            id: 1_700_000_000_000,
+            start_auction_at: 1_700_000_000_000,
+            end_auction_at: 1_700_001_800_000,
+            authority: authority.pubkey(),
+            treasury_mint: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            bidders_chest: Pubkey::new_unique(),
+            starting_price: 3 * ONE_SOL,
+            next_bid: 3 * ONE_SOL * 105 / 100,
+            claimed: 0,
+            state: AuctionState::Active,
+            last_bidder: last_bidder.pubkey(),
+            bump: 255,
+        };
+
+        /* Accounts created before the layout changed are exactly `size_of` the old struct */
+        let mut data = BoyncAuction2::discriminator().to_vec();
+        data.extend(legacy.try_to_vec().unwrap());
+        data.resize(8 + BoyncAuction2Legacy::AUCTION_SIZE, 0);
+
+        let mut program = program_test();
+        program.add_account(
+            auction,
+            SolanaAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: boync_anchor_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+        let mut context = program.start_with_context().await;
+        airdrop(&mut context, &authority.pubkey(), ONE_SOL).await.unwrap();
+
+        /* Only the auction authority can migrate it */
+        let someone = Keypair::new();
+        airdrop(&mut context, &someone.pubkey(), ONE_SOL).await.unwrap();
+        let (_, tx) = boync_migrate_auction2(&mut context, &auction, &someone);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::InvalidAuthority);
+
+        let (_, tx) = boync_migrate_auction2(&mut context, &auction, &authority);
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let account = context.banks_client.get_account(auction).await.unwrap().unwrap();
+        assert_eq!(account.data.len(), 8 + BoyncAuction2::AUCTION_SIZE);
+        assert!(account.lamports >= Rent::default().minimum_balance(account.data.len()));
+
+        let auction_house_data = boync_get_auction_data(&mut context, &auction).await;
+        assert_eq!(auction_house_data.authority, authority.pubkey());
+        assert_eq!(auction_house_data.last_bidder, last_bidder.pubkey());
+        assert_eq!(auction_house_data.next_bid, legacy.next_bid);
+        assert_eq!(auction_house_data.highest_bid, 3 * ONE_SOL);
+        assert!(auction_house_data.state == AuctionState::Active);
+        assert!(auction_house_data.reserve_price.is_none());
+        assert!(auction_house_data.reserve_met());
+        assert_eq!(auction_house_data.crank_fee_bps, 0);
+
+        /* An account is migrated once */
+        context.get_new_latest_blockhash().await.unwrap();
+        let (_, tx) = boync_migrate_auction2(&mut context, &auction, &authority);
+        let err = context.banks_client.process_transaction(tx).await.unwrap_err();
+        assert_auction_error(err, AuctionError::AuctionAlreadyMigrated);
+    }
+
 }
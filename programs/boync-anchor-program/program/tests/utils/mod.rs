@@ -13,10 +13,13 @@ pub use mpl_token_metadata::instruction;
 use mpl_token_metadata::state::CollectionDetails;
 use solana_program_test::*;
 use solana_sdk::{
-    account::Account, program_pack::Pack, pubkey::Pubkey, signature::Signer,
-    signer::keypair::Keypair, system_instruction, transaction::Transaction,
-
+    account::Account,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    instruction::InstructionError, program_pack::Pack, pubkey::Pubkey, rent::Rent,
+    signature::Signer, signer::keypair::{keypair_from_seed, Keypair}, system_instruction,
+    transaction::{Transaction, TransactionError},
 };
+use boync_anchor_program::errors::AuctionError;
 
 use anchor_spl::token::spl_token;
 
@@ -24,14 +27,69 @@ pub const DEFAULT_COLLECTION_DETAILS: Option<CollectionDetails> =
     Some(CollectionDetails::V1 { size: 0 });
 
 pub fn program_test() -> ProgramTest {
-    let mut program = ProgramTest::new("boync_anchor_program", boync_anchor_program::id(), None);
+    let mut program = ProgramTest::default();
     program.set_compute_max_units(1_200_000);
 
+    /* Deployed through the upgradeable loader, `initialize_config` checks the upgrade authority */
+    add_upgradeable_program(
+        &mut program,
+        "boync_anchor_program",
+        boync_anchor_program::id(),
+        &program_upgrade_authority().pubkey(),
+    );
     program.add_program("mpl_token_metadata", mpl_token_metadata::id(), None);
 
     program
 }
 
+/// Upgrade authority of the program under test, the only key that can initialize the config.
+pub fn program_upgrade_authority() -> Keypair {
+    keypair_from_seed(&[7; 32]).unwrap()
+}
+
+pub fn find_program_data_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
+}
+
+fn add_upgradeable_program(
+    program: &mut ProgramTest,
+    program_name: &str,
+    program_id: Pubkey,
+    upgrade_authority: &Pubkey,
+) {
+    let elf = read_file(
+        find_file(&format!("{}.so", program_name))
+            .unwrap_or_else(|| panic!("{}.so not found, build the program first", program_name)),
+    );
+    let rent = Rent::default();
+    let program_data_address = find_program_data_address(&program_id);
+
+    let mut program_account = Account::new_data(
+        rent.minimum_balance(UpgradeableLoaderState::size_of_program()),
+        &UpgradeableLoaderState::Program {
+            programdata_address: program_data_address,
+        },
+        &bpf_loader_upgradeable::id(),
+    )
+    .unwrap();
+    program_account.executable = true;
+    program.add_account(program_id, program_account);
+
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let mut program_data_account = Account::new_data_with_space(
+        rent.minimum_balance(metadata_len + elf.len()),
+        &UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(*upgrade_authority),
+        },
+        metadata_len + elf.len(),
+        &bpf_loader_upgradeable::id(),
+    )
+    .unwrap();
+    program_data_account.data[metadata_len..].copy_from_slice(&elf);
+    program.add_account(program_data_address, program_data_account);
+}
+
 pub async fn get_account(context: &mut ProgramTestContext, pubkey: &Pubkey) -> Account {
     context
         .banks_client
@@ -41,6 +99,22 @@ pub async fn get_account(context: &mut ProgramTestContext, pubkey: &Pubkey) -> A
         .expect("account empty")
 }
 
+/// Asserts a failed transaction's first custom error is `expected`.
+pub fn assert_auction_error(error: BanksClientError, expected: AuctionError) {
+    let transaction_error = match error {
+        BanksClientError::TransactionError(err) => err,
+        BanksClientError::SimulationError { err, .. } => err,
+        err => panic!("Unexpected error: {:?}", err),
+    };
+
+    match transaction_error {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, u32::from(expected))
+        }
+        err => panic!("Unexpected transaction error: {:?}", err),
+    }
+}
+
 // Boync Edit - we don't need this yet.
 //
 // pub async fn get_mint(context: &mut ProgramTestContext, pubkey: &Pubkey) -> Mint {
@@ -1,10 +1,11 @@
 use super::digital_asset::*;
 use super::constants::*;
+use super::find_program_data_address;
 use anchor_lang::*;
 use solana_program_test::*;
 
 use anchor_client::solana_sdk::{
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     system_program, sysvar,
@@ -13,26 +14,84 @@ use anchor_client::solana_sdk::{
 
 use boync_anchor_program::{
     accounts::{
+        InitializeConfig as InitializeConfigAccounts, UpdateConfig as UpdateConfigAccounts,
         InitializeAuction2 as InitializeAuction2Accounts, UpdateAuction2 as UpdateAuction2Accounts,
         InitializeAuction3 as InitializeAuction3Accounts, UpdateAuction3 as UpdateAuction3Accounts,
         ClaimRewards as ClaimRewardsAccounts,
         ClaimRewards3 as ClaimRewards3Accounts,
+        CancelAuction as CancelAuctionAccounts, CancelAuction3 as CancelAuction3Accounts,
+        UpdateAuctionConfig as UpdateAuctionConfigAccounts,
+        InitializeEnglishAuction as InitializeEnglishAuctionAccounts, PlaceBid as PlaceBidAccounts,
+        PlaceProxyBid as PlaceProxyBidAccounts, WithdrawProxyBid as WithdrawProxyBidAccounts,
+        PlaceBid2 as PlaceBid2Accounts,
+        Settle as SettleAccounts, Settle3 as Settle3Accounts,
+        EndAuction as EndAuctionAccounts,
+        CloseBidRecord as CloseBidRecordAccounts,
+        WithdrawProceeds3 as WithdrawProceeds3Accounts,
+        OfferRunnerUp3 as OfferRunnerUp3Accounts, ReclaimDefaulted3 as ReclaimDefaulted3Accounts,
+        RefundDeposit3 as RefundDeposit3Accounts,
+        WithdrawChest3 as WithdrawChest3Accounts,
+        InitializeDutchAuction as InitializeDutchAuctionAccounts, BuyDutch as BuyDutchAccounts,
+        ClaimRewardsDutch as ClaimRewardsDutchAccounts,
+        InitializeSealedAuction as InitializeSealedAuctionAccounts, CommitBid as CommitBidAccounts,
+        RevealBid as RevealBidAccounts, WithdrawSealedBid as WithdrawSealedBidAccounts,
+        ClaimRewardsSealed as ClaimRewardsSealedAccounts,
+        RefundBid as RefundBidAccounts,
+        BuyNow as BuyNowAccounts, BuyNow3 as BuyNow3Accounts, BuyNowEnglish as BuyNowEnglishAccounts,
+        MigrateAuction2 as MigrateAuction2Accounts, MigrateAuction3 as MigrateAuction3Accounts,
     },
     instruction::{
+        InitializeConfig as InitializeConfigData, UpdateConfig as UpdateConfigData,
         InitializeAuction2 as InitializeAuction2Data, UpdateAuction2 as UpdateAuction2Data,
+        Initialize as InitializeData,
         InitializeAuction3 as InitializeAuction3Data, UpdateAuction3 as UpdateAuction3Data,
         ClaimRewards as ClaimRewardsData,
-        ClaimRewards3 as ClaimRewards3Data
+        ClaimRewards3 as ClaimRewards3Data,
+        CancelAuction as CancelAuctionData, CancelAuction3 as CancelAuction3Data,
+        UpdateAuctionConfig as UpdateAuctionConfigData,
+        InitializeEnglishAuction as InitializeEnglishAuctionData, PlaceBid as PlaceBidData,
+        PlaceProxyBid as PlaceProxyBidData, WithdrawProxyBid as WithdrawProxyBidData,
+        PlaceBid2 as PlaceBid2Data,
+        Settle as SettleData, Settle3 as Settle3Data,
+        End as EndData,
+        CloseBidRecord as CloseBidRecordData,
+        WithdrawProceeds3 as WithdrawProceeds3Data,
+        OfferRunnerUp3 as OfferRunnerUp3Data, ReclaimDefaulted3 as ReclaimDefaulted3Data,
+        RefundDeposit3 as RefundDeposit3Data,
+        WithdrawChest3 as WithdrawChest3Data,
+        InitializeDutchAuction as InitializeDutchAuctionData, BuyDutch as BuyDutchData,
+        ClaimRewardsDutch as ClaimRewardsDutchData,
+        InitializeSealedAuction as InitializeSealedAuctionData, CommitBid as CommitBidData,
+        RevealBid as RevealBidData, WithdrawSealedBid as WithdrawSealedBidData,
+        ClaimRewardsSealed as ClaimRewardsSealedData,
+        RefundBid as RefundBidData,
+        BuyNow as BuyNowData, BuyNow3 as BuyNow3Data, BuyNowEnglish as BuyNowEnglishData,
+        MigrateAuction2 as MigrateAuction2Data, MigrateAuction3 as MigrateAuction3Data,
     },
     pda::{
-        find_boync_auction_address, find_boync_bidder_state_address,
+        find_boync_config_address, find_boync_auction_address, find_boync_bidder_state_address,
+        find_boync_bid_counter_address, find_boync_bid_record_address,
         find_boync_bidders_chest_address, find_boync_bidders_chest_address_with_token_mint, find_boync_treasury_address,
+        find_boync_defaulter_address, find_boync_bid_deposit_address, find_boync_proxy_bid_address,
+        find_boync_sealed_bid_address,
     },
-    account::{BoyncAuction2, BoyncAuction3, BoyncUserBid},
+    account::{BoyncConfig, BoyncAuction2, BoyncAuction3, BoyncEnglishAuction, BoyncDutchAuction, BoyncSealedAuction, BoyncSealedBid, SellerSplits, BoyncUserBid, BoyncBidderCounter, BoyncDefaulter, BidGate},
 };
 use mpl_token_metadata::pda::{find_master_edition_account, find_token_record_account};
+use spl_associated_token_account::get_associated_token_address;
 
 
+pub async fn boync_get_config_data(context: &mut ProgramTestContext) -> BoyncConfig {
+    let config_acc = context
+        .banks_client
+        .get_account(find_boync_config_address().0)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    BoyncConfig::try_deserialize(&mut config_acc.data.as_ref()).unwrap()
+}
+
 pub async fn boync_get_auction_data(
     context: &mut ProgramTestContext,
     auction: &Pubkey,
@@ -61,6 +120,64 @@ pub async fn boync_get_auction_data_v3(
     BoyncAuction3::try_deserialize(&mut auction_house_acc.data.as_ref()).unwrap()
 }
 
+pub async fn boync_get_auction_data_english(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+) -> BoyncEnglishAuction {
+    let auction_house_acc = context
+        .banks_client
+        .get_account(*auction)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    BoyncEnglishAuction::try_deserialize(&mut auction_house_acc.data.as_ref()).unwrap()
+}
+
+pub async fn boync_get_auction_data_dutch(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+) -> BoyncDutchAuction {
+    let auction_house_acc = context
+        .banks_client
+        .get_account(*auction)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    BoyncDutchAuction::try_deserialize(&mut auction_house_acc.data.as_ref()).unwrap()
+}
+
+pub async fn boync_get_auction_data_sealed(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+) -> BoyncSealedAuction {
+    let auction_house_acc = context
+        .banks_client
+        .get_account(*auction)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    BoyncSealedAuction::try_deserialize(&mut auction_house_acc.data.as_ref()).unwrap()
+}
+
+pub async fn boync_get_sealed_bid_data(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidder: &Pubkey,
+) -> BoyncSealedBid {
+    let (bidder_state, _) = find_boync_sealed_bid_address(auction, bidder);
+    let bidder_acc = context
+        .banks_client
+        .get_account(bidder_state)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    BoyncSealedBid::try_deserialize(&mut bidder_acc.data.as_ref()).unwrap()
+}
+
 pub async fn boync_get_bidder_state_data(
     context: &mut ProgramTestContext,
     bidder: &Pubkey,
@@ -75,11 +192,42 @@ pub async fn boync_get_bidder_state_data(
     BoyncUserBid::try_deserialize(&mut bidder_acc.data.as_ref()).unwrap()
 }
 
+pub async fn boync_get_defaulter_data(
+    context: &mut ProgramTestContext,
+    bidder: &Pubkey,
+) -> BoyncDefaulter {
+    let (defaulter, _) = find_boync_defaulter_address(bidder);
+    let defaulter_acc = context
+        .banks_client
+        .get_account(defaulter)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    BoyncDefaulter::try_deserialize(&mut defaulter_acc.data.as_ref()).unwrap()
+}
+
+pub async fn boync_get_bidder_counter_data(
+    context: &mut ProgramTestContext,
+    bidder_counter: &Pubkey,
+) -> BoyncBidderCounter {
+    let bidder_counter_acc = context
+        .banks_client
+        .get_account(*bidder_counter)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    BoyncBidderCounter::try_deserialize(&mut bidder_counter_acc.data.as_ref()).unwrap()
+}
+
 pub fn boync_update_auction_claim(
     context: &mut ProgramTestContext,
     digital_asset: &DigitalAsset,
     auction: &Pubkey,
+    bidders_chest: &Pubkey,
     treasury_token: &Pubkey,
+    authority: &Pubkey,
     winner_token: &Pubkey,
     winner: &Keypair,
 ) -> (ClaimRewardsAccounts, Transaction) {
@@ -100,6 +248,8 @@ pub fn boync_update_auction_claim(
         state: *auction,
         treasury: *treasury_token,
         treasury_mint: *mint,
+        bidders_chest: *bidders_chest,
+        authority: *authority,
         winner: winner.pubkey(),
         winner_token_account: *winner_token,
         metadata: digital_asset.metadata,
@@ -136,14 +286,97 @@ pub fn boync_update_auction_claim(
     )
 }
 
-pub fn boync_update_auction_claim_v3(
+pub fn boync_initialize_config(
+    context: &mut ProgramTestContext,
+    fee_bps: u16,
+    fee_recipient: &Pubkey,
+    admin: &Keypair,
+) -> (InitializeConfigAccounts, Transaction) {
+    let (config, _) = find_boync_config_address();
+
+    let accounts = InitializeConfigAccounts {
+        config,
+        admin: admin.pubkey(),
+        program: boync_anchor_program::id(),
+        program_data: find_program_data_address(&boync_anchor_program::id()),
+        system_program: system_program::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = InitializeConfigData {
+        fee_bps,
+        fee_recipient: *fee_recipient,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&admin.pubkey()),
+            &[admin],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_update_config(
+    context: &mut ProgramTestContext,
+    fee_bps: u16,
+    fee_recipient: &Pubkey,
+    new_admin: Option<&Pubkey>,
+    admin: &Keypair,
+) -> (UpdateConfigAccounts, Transaction) {
+    let (config, _) = find_boync_config_address();
+
+    let accounts = UpdateConfigAccounts {
+        config,
+        admin: admin.pubkey(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = UpdateConfigData {
+        fee_bps,
+        fee_recipient: *fee_recipient,
+        new_admin: new_admin.copied(),
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&admin.pubkey()),
+            &[admin],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_settle(
     context: &mut ProgramTestContext,
     digital_asset: &DigitalAsset,
     auction: &Pubkey,
     treasury_token: &Pubkey,
-    winner_token: &Pubkey,
-    winner: &Keypair,
-) -> (ClaimRewards3Accounts, Transaction) {
+    bidders_chest: &Pubkey,
+    authority: &Pubkey,
+    fee_recipient: &Pubkey,
+    recipient: &Pubkey,
+    creators: &[Pubkey],
+    keeper: &Keypair,
+) -> (SettleAccounts, Transaction) {
 
     let mint = &digital_asset.mint.pubkey();
 
@@ -154,15 +387,21 @@ pub fn boync_update_auction_claim_v3(
         edition
     };
 
+    let recipient_token = get_associated_token_address(recipient, mint);
     let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
-    let (destination_token_record, _bump) = find_token_record_account(mint, &winner_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &recipient_token);
 
-    let accounts = ClaimRewards3Accounts {
+    let accounts = SettleAccounts {
         state: *auction,
         treasury: *treasury_token,
         treasury_mint: *mint,
-        winner: winner.pubkey(),
-        winner_token_account: *winner_token,
+        bidders_chest: *bidders_chest,
+        authority: *authority,
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        recipient: *recipient,
+        recipient_token_account: recipient_token,
+        keeper: keeper.pubkey(),
         metadata: digital_asset.metadata,
         edition,
         owner_token_record,
@@ -176,9 +415,10 @@ pub fn boync_update_auction_claim_v3(
         rent: sysvar::rent::id(),
         sysvar_instructions: sysvar::instructions::id(),
     };
-    let accounts_meta = accounts.to_account_metas(None);
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
 
-    let data = ClaimRewards3Data{}.data();
+    let data = SettleData{}.data();
 
     let instruction = Instruction {
         program_id: boync_anchor_program::id(),
@@ -190,32 +430,58 @@ pub fn boync_update_auction_claim_v3(
         accounts,
         Transaction::new_signed_with_payer(
             &[instruction],
-            Some(&winner.pubkey()),
-            &[winner],
+            Some(&keeper.pubkey()),
+            &[keeper],
             context.last_blockhash,
         ),
     )
 }
 
-pub fn boync_update_auction_bid(
+pub fn boync_settle3(
     context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
     auction: &Pubkey,
-    bidders_chest: &Pubkey,
-    bidder: &Keypair,
-    ts: &i64,
-) -> (UpdateAuction2Accounts, Transaction) {
-    let (bidder_state, _) = find_boync_bidder_state_address(auction, &bidder.pubkey(), ts);
-    let accounts = UpdateAuction2Accounts {
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    keeper: &Keypair,
+) -> (Settle3Accounts, Transaction) {
+
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let authority_token = get_associated_token_address(authority, mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &authority_token);
+
+    let accounts = Settle3Accounts {
         state: *auction,
-        bidders_chest: *bidders_chest,
-        bidder_state,
-        bidder: bidder.pubkey(),
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        authority_token_account: authority_token,
+        keeper: keeper.pubkey(),
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
         system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
         rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
     };
     let accounts_meta = accounts.to_account_metas(None);
 
-    let data = UpdateAuction2Data { ts: *ts }.data();
+    let data = Settle3Data{}.data();
 
     let instruction = Instruction {
         program_id: boync_anchor_program::id(),
@@ -227,40 +493,74 @@ pub fn boync_update_auction_bid(
         accounts,
         Transaction::new_signed_with_payer(
             &[instruction],
-            Some(&bidder.pubkey()),
-            &[bidder],
+            Some(&keeper.pubkey()),
+            &[keeper],
             context.last_blockhash,
         ),
     )
 }
 
-pub fn boync_update_auction_bid_v3(
+pub fn boync_end(
     context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
     auction: &Pubkey,
-    chest: &Pubkey,
-    chest_mint: &Pubkey,
-    user_token_account: &Pubkey,
-    bidder: &Keypair,
-    ts: &i64,
-) -> (UpdateAuction3Accounts, Transaction) {
-    let (bidder_state, _) = find_boync_bidder_state_address(auction, &bidder.pubkey(), ts);
-
-    println!("[auction]: {:?}, [bidders_chest]: {:?}, [chest_mint]: {:?}", auction, chest, chest_mint);
+    timestamp: &i64,
+    fee_recipient: &Pubkey,
+    creators: &[Pubkey],
+    authority: &Keypair,
+) -> (EndAuctionAccounts, Transaction) {
+    let (bidders_chest, bidders_chest_bump) =
+        find_boync_bidders_chest_address(&authority.pubkey(), timestamp);
 
-    let accounts = UpdateAuction3Accounts {
+    let accounts = EndAuctionAccounts {
         state: *auction,
-        chest: *chest,
-        chest_mint: *chest_mint,
-        bidder_state,
-        bidder_token_account: *user_token_account,
-        bidder: bidder.pubkey(),
+        bidders_chest,
+        authority: authority.pubkey(),
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        metadata: digital_asset.metadata,
         system_program: system_program::id(),
-        token_program: spl_token::id(),
         rent: sysvar::rent::id(),
     };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    let data = EndData {
+        bidder_chest_bump: bidders_chest_bump,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_close_bid_record(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidder_state: &Pubkey,
+    bidder: &Keypair,
+) -> (CloseBidRecordAccounts, Transaction) {
+    let accounts = CloseBidRecordAccounts {
+        auction: *auction,
+        bidder_state: *bidder_state,
+        bidder: bidder.pubkey(),
+    };
     let accounts_meta = accounts.to_account_metas(None);
 
-    let data = UpdateAuction3Data { ts: *ts }.data();
+    let data = CloseBidRecordData{}.data();
 
     let instruction = Instruction {
         program_id: boync_anchor_program::id(),
@@ -279,19 +579,15 @@ pub fn boync_update_auction_bid_v3(
     )
 }
 
-pub fn boync_initialize_2(
+pub fn boync_reclaim_defaulted3(
     context: &mut ProgramTestContext,
-    creator: &Keypair,
     digital_asset: &DigitalAsset,
     auction: &Pubkey,
-    auction_bump: u8,
     treasury_token: &Pubkey,
-    bidders_chest: &Pubkey,
-    timestamp: &i64,
-    creator_token: &Pubkey,
-    auction_duration: Option<&i64>
-) -> (InitializeAuction2Accounts, Transaction) {
-    // let token = &digital_asset.token.pubkey();
+    defaulted_bidder: &Pubkey,
+    authority: &Keypair,
+) -> (ReclaimDefaulted3Accounts, Transaction) {
+
     let mint = &digital_asset.mint.pubkey();
 
     let edition = if let Some(edition) = digital_asset.master_edition {
@@ -301,21 +597,23 @@ pub fn boync_initialize_2(
         edition
     };
 
-    let (owner_token_record, _) = find_token_record_account(mint, &creator_token);
-    let (destination_token_record, _bump) = find_token_record_account(mint, &treasury_token);
+    let authority_token = get_associated_token_address(&authority.pubkey(), mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &authority_token);
 
-    let accounts = InitializeAuction2Accounts {
+    let accounts = ReclaimDefaulted3Accounts {
         state: *auction,
         treasury: *treasury_token,
-        bidders_chest: *bidders_chest,
-        signer: creator.pubkey(),
         treasury_mint: *mint,
+        authority: authority.pubkey(),
+        authority_token_account: authority_token,
+        defaulter: find_boync_defaulter_address(defaulted_bidder).0,
+        defaulter_deposit: find_boync_bid_deposit_address(auction, defaulted_bidder).0,
         metadata: digital_asset.metadata,
         edition,
         owner_token_record,
         destination_token_record,
-        auth_rules: mpl_token_auth_rules::id(), // !!!NOT USED
-        signer_token_account: *creator_token,
+        auth_rules: mpl_token_auth_rules::id(),
         system_program: system_program::id(),
         token_program: spl_token::id(),
         associated_token_program: spl_associated_token_account::id(),
@@ -326,20 +624,7 @@ pub fn boync_initialize_2(
     };
     let accounts_meta = accounts.to_account_metas(None);
 
-    let duration = if let Some(duration) = auction_duration {
-        duration
-    } else {
-        &THIRTY_MINUTES_IN_MSEC
-    };
-
-    let data = InitializeAuction2Data {
-        app_idx: *timestamp,
-        state_bump: auction_bump,
-        fp: 3 * ONE_SOL,
-        start_at: *timestamp,
-        end_at: *(timestamp) + duration,
-    }
-    .data();
+    let data = ReclaimDefaulted3Data{}.data();
 
     let instruction = Instruction {
         program_id: boync_anchor_program::id(),
@@ -351,26 +636,1416 @@ pub fn boync_initialize_2(
         accounts,
         Transaction::new_signed_with_payer(
             &[instruction],
-            Some(&creator.pubkey()),
-            &[creator],
+            Some(&authority.pubkey()),
+            &[authority],
             context.last_blockhash,
         ),
     )
 }
 
-pub fn boync_initialize_3(
+pub fn boync_offer_runner_up3(
     context: &mut ProgramTestContext,
-    creator: &Keypair,
-    digital_asset: &DigitalAsset,
-    bid_token_mint: &Pubkey,
     auction: &Pubkey,
-    auction_bump: u8,
+    defaulted_bidder: &Pubkey,
+    authority: &Keypair,
+) -> (OfferRunnerUp3Accounts, Transaction) {
+    let accounts = OfferRunnerUp3Accounts {
+        state: *auction,
+        authority: authority.pubkey(),
+        defaulter: find_boync_defaulter_address(defaulted_bidder).0,
+        defaulter_deposit: find_boync_bid_deposit_address(auction, defaulted_bidder).0,
+        system_program: system_program::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = OfferRunnerUp3Data{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_refund_deposit3(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidder: &Pubkey,
+    payer: &Keypair,
+) -> (RefundDeposit3Accounts, Transaction) {
+    let accounts = RefundDeposit3Accounts {
+        state: *auction,
+        deposit: find_boync_bid_deposit_address(auction, bidder).0,
+        bidder: *bidder,
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = RefundDeposit3Data{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_withdraw_proceeds3(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    chest: &Pubkey,
+    authority: &Keypair,
+) -> (WithdrawProceeds3Accounts, Transaction) {
+    let accounts = WithdrawProceeds3Accounts {
+        state: *auction,
+        chest: *chest,
+        authority: authority.pubkey(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = WithdrawProceeds3Data{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_withdraw_chest3(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    chest: &Pubkey,
+    authority_token: &Pubkey,
+    authority: &Keypair,
+) -> (WithdrawChest3Accounts, Transaction) {
+    let accounts = WithdrawChest3Accounts {
+        state: *auction,
+        chest: *chest,
+        authority_token_account: *authority_token,
+        authority: authority.pubkey(),
+        token_program: spl_token::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = WithdrawChest3Data{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_cancel_auction(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority_token: &Pubkey,
+    authority: &Keypair,
+) -> (CancelAuctionAccounts, Transaction) {
+
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &authority_token);
+
+    let accounts = CancelAuctionAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: authority.pubkey(),
+        authority_token_account: *authority_token,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = CancelAuctionData{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_cancel_auction3(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    chest: &Pubkey,
+    authority_token: &Pubkey,
+    authority_chest_token: Option<&Pubkey>,
+    authority: &Keypair,
+) -> (CancelAuction3Accounts, Transaction) {
+
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &authority_token);
+
+    let accounts = CancelAuction3Accounts {
+        state: *auction,
+        treasury: *treasury_token,
+        chest: *chest,
+        treasury_mint: *mint,
+        authority: authority.pubkey(),
+        authority_token_account: *authority_token,
+        authority_chest_token_account: authority_chest_token.copied(),
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = CancelAuction3Data{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_update_auction_config(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    authority: &Keypair,
+    fp: u64,
+    start_at: i64,
+    end_at: i64,
+) -> (UpdateAuctionConfigAccounts, Transaction) {
+
+    let accounts = UpdateAuctionConfigAccounts {
+        state: *auction,
+        authority: authority.pubkey(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = UpdateAuctionConfigData {
+        fp,
+        start_at,
+        end_at,
+        reserve_price: None,
+        buy_now_price: None,
+        time_extension: None,
+        bid_increment: None,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_update_auction_claim_v3(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    fee_recipient: &Pubkey,
+    winner_token: &Pubkey,
+    creators: &[Pubkey],
+    winner: &Keypair,
+) -> (ClaimRewards3Accounts, Transaction) {
+
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &winner_token);
+
+    let accounts = ClaimRewards3Accounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        winner: winner.pubkey(),
+        winner_token_account: *winner_token,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    let data = ClaimRewards3Data{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&winner.pubkey()),
+            &[winner],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_update_auction_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidders_chest: &Pubkey,
+    bidder: &Keypair,
+    ts: &i64,
+) -> (UpdateAuction2Accounts, Transaction) {
+    let (bidder_state, _) = find_boync_bidder_state_address(auction, &bidder.pubkey(), ts);
+    let accounts = UpdateAuction2Accounts {
+        state: *auction,
+        bidders_chest: *bidders_chest,
+        bidder_state,
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = UpdateAuction2Data { _ts: *ts }.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_place_bid2(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidders_chest: &Pubkey,
+    bidder: &Keypair,
+    seq: &u64,
+) -> (PlaceBid2Accounts, Transaction) {
+    let (bidder_counter, _) = find_boync_bid_counter_address(auction, &bidder.pubkey());
+    let (bidder_state, _) = find_boync_bid_record_address(auction, &bidder.pubkey(), seq);
+    let accounts = PlaceBid2Accounts {
+        state: *auction,
+        bidders_chest: *bidders_chest,
+        bidder_counter,
+        bidder_state,
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = PlaceBid2Data {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_update_auction_bid_v3(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    chest: &Pubkey,
+    chest_mint: &Pubkey,
+    user_token_account: &Pubkey,
+    bidder: &Keypair,
+    ts: &i64,
+) -> (UpdateAuction3Accounts, Transaction) {
+    let (bidder_state, _) = find_boync_bidder_state_address(auction, &bidder.pubkey(), ts);
+
+    println!("[auction]: {:?}, [bidders_chest]: {:?}, [chest_mint]: {:?}", auction, chest, chest_mint);
+
+    let accounts = UpdateAuction3Accounts {
+        state: *auction,
+        chest: *chest,
+        chest_mint: *chest_mint,
+        bidder_state,
+        deposit: find_boync_bid_deposit_address(auction, &bidder.pubkey()).0,
+        bidder_token_account: *user_token_account,
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        rent: sysvar::rent::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = UpdateAuction3Data { _ts: *ts }.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+/// Optional `initialize_auction2` settings, all unset by default.
+#[derive(Default)]
+pub struct Auction2Options {
+    pub reserve_price: Option<u64>,
+    pub buy_now_price: Option<u64>,
+    pub crank_fee_bps: Option<u16>,
+    pub seller_splits: Option<SellerSplits>,
+    pub bid_gate: Option<BidGate>,
+}
+
+pub fn boync_initialize_2(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    auction_bump: u8,
+    treasury_token: &Pubkey,
+    bidders_chest: &Pubkey,
+    timestamp: &i64,
+    creator_token: &Pubkey,
+    auction_duration: Option<&i64>
+) -> (InitializeAuction2Accounts, Transaction) {
+    boync_initialize_2_with(
+        context,
+        creator,
+        digital_asset,
+        auction,
+        auction_bump,
+        treasury_token,
+        bidders_chest,
+        timestamp,
+        creator_token,
+        auction_duration,
+        Auction2Options::default(),
+    )
+}
+
+pub fn boync_initialize_2_with(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    auction_bump: u8,
+    treasury_token: &Pubkey,
+    bidders_chest: &Pubkey,
+    timestamp: &i64,
+    creator_token: &Pubkey,
+    auction_duration: Option<&i64>,
+    options: Auction2Options,
+) -> (InitializeAuction2Accounts, Transaction) {
+    // let token = &digital_asset.token.pubkey();
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &creator_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &treasury_token);
+
+    let accounts = InitializeAuction2Accounts {
+        state: *auction,
+        treasury: *treasury_token,
+        bidders_chest: *bidders_chest,
+        signer: creator.pubkey(),
+        treasury_mint: *mint,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(), // !!!NOT USED
+        signer_token_account: *creator_token,
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let duration = if let Some(duration) = auction_duration {
+        duration
+    } else {
+        &THIRTY_MINUTES_IN_MSEC
+    };
+
+    let data = InitializeAuction2Data {
+        app_idx: *timestamp,
+        state_bump: auction_bump,
+        fp: 3 * ONE_SOL,
+        start_at: *timestamp,
+        end_at: *(timestamp) + duration,
+        reserve_price: options.reserve_price,
+        buy_now_price: options.buy_now_price,
+        time_extension: None,
+        bid_increment: None,
+        crank_fee_bps: options.crank_fee_bps,
+        seller_splits: options.seller_splits,
+        bid_gate: options.bid_gate,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&creator.pubkey()),
+            &[creator],
+            context.last_blockhash,
+        ),
+    )
+}
+
+/// Lists through the legacy v2 `initialize`, which moves the token with a plain SPL transfer.
+pub fn boync_initialize_legacy_2(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    auction_bump: u8,
+    treasury_token: &Pubkey,
+    bidders_chest: &Pubkey,
+    timestamp: &i64,
+    creator_token: &Pubkey,
+) -> (InitializeAuction2Accounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &creator_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &treasury_token);
+
+    let accounts = InitializeAuction2Accounts {
+        state: *auction,
+        treasury: *treasury_token,
+        bidders_chest: *bidders_chest,
+        signer: creator.pubkey(),
+        treasury_mint: *mint,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(), // !!!NOT USED
+        signer_token_account: *creator_token,
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = InitializeData {
+        app_idx: *timestamp,
+        state_bump: auction_bump,
+        fp: 3 * ONE_SOL,
+        start_at: *timestamp,
+        end_at: *(timestamp) + THIRTY_MINUTES_IN_MSEC,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&creator.pubkey()),
+            &[creator],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_initialize_3(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    digital_asset: &DigitalAsset,
+    bid_token_mint: &Pubkey,
+    auction: &Pubkey,
+    auction_bump: u8,
+    treasury_token: &Pubkey,
+    bidders_chest: &Pubkey,
+    timestamp: &i64,
+    creator_token: &Pubkey,
+    auction_duration: Option<&i64>,
+    payment_window: Option<i64>,
+    bid_deposit: Option<u64>,
+    buy_now_price: Option<u64>,
+) -> (InitializeAuction3Accounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &creator_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &treasury_token);
+
+    let accounts = InitializeAuction3Accounts {
+        state: *auction,
+        treasury: *treasury_token,
+        chest: *bidders_chest,
+        signer: creator.pubkey(),
+        treasury_mint: *mint,
+        chest_mint: *bid_token_mint,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(), // !!!NOT USED
+        signer_token_account: *creator_token,
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let duration = if let Some(duration) = auction_duration {
+        duration
+    } else {
+        &THIRTY_MINUTES_IN_MSEC
+    };
+
+    let data = InitializeAuction3Data {
+        app_idx: *timestamp,
+        state_bump: auction_bump,
+        start_at: *timestamp,
+        end_at: *(timestamp) + duration,
+        reserve_price: None,
+        buy_now_price,
+        time_extension: None,
+        bid_increment: None,
+        bid_token_cost: None,
+        bid_cost_rise: None,
+        seller_splits: None,
+        payment_window,
+        bid_deposit,
+        bid_gate: None,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&creator.pubkey()),
+            &[creator],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_place_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    previous_bidder: Option<&Pubkey>,
+    bidder: &Keypair,
+    seq: &u64,
+    amount: u64,
+    gate_accounts: &[Pubkey],
+) -> (PlaceBidAccounts, Transaction) {
+    let (bidder_counter, _) = find_boync_bid_counter_address(auction, &bidder.pubkey());
+    let (bidder_state, _) = find_boync_bid_record_address(auction, &bidder.pubkey(), seq);
+    let leader = previous_bidder.copied().unwrap_or_else(system_program::id);
+    let accounts = PlaceBidAccounts {
+        state: *auction,
+        bidder_counter,
+        bidder_state,
+        previous_bidder: previous_bidder.copied(),
+        leader_proxy: find_boync_proxy_bid_address(auction, &leader).0,
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(gate_accounts.iter().map(|account| AccountMeta::new_readonly(*account, false)));
+
+    let data = PlaceBidData { _ts: 0, amount }.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_place_proxy_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    previous_bidder: Option<&Pubkey>,
+    bidder: &Keypair,
+    max_bid: u64,
+) -> (PlaceProxyBidAccounts, Transaction) {
+    let leader = previous_bidder.copied().unwrap_or_else(system_program::id);
+    let accounts = PlaceProxyBidAccounts {
+        state: *auction,
+        proxy: find_boync_proxy_bid_address(auction, &bidder.pubkey()).0,
+        leader_proxy: find_boync_proxy_bid_address(auction, &leader).0,
+        previous_bidder: previous_bidder.copied(),
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = PlaceProxyBidData { max_bid }.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_withdraw_proxy_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidder: &Keypair,
+) -> (WithdrawProxyBidAccounts, Transaction) {
+    let accounts = WithdrawProxyBidAccounts {
+        state: *auction,
+        proxy: find_boync_proxy_bid_address(auction, &bidder.pubkey()).0,
+        bidder: bidder.pubkey(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = WithdrawProxyBidData{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_initialize_english(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    auction_bump: u8,
+    treasury_token: &Pubkey,
+    timestamp: &i64,
+    creator_token: &Pubkey,
+    starting_price: u64,
+    min_bid_increment: u64,
+    buy_now_price: Option<u64>,
+    bid_gate: Option<BidGate>,
+) -> (InitializeEnglishAuctionAccounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &creator_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &treasury_token);
+
+    let accounts = InitializeEnglishAuctionAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        signer: creator.pubkey(),
+        treasury_mint: *mint,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(), // !!!NOT USED
+        signer_token_account: *creator_token,
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = InitializeEnglishAuctionData {
+        app_idx: *timestamp,
+        state_bump: auction_bump,
+        starting_price,
+        min_bid_increment,
+        start_at: *timestamp,
+        end_at: *(timestamp) + THIRTY_MINUTES_IN_MSEC,
+        buy_now_price,
+        time_extension: None,
+        seller_splits: None,
+        bid_gate,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&creator.pubkey()),
+            &[creator],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_initialize_dutch(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    auction_bump: u8,
+    treasury_token: &Pubkey,
+    timestamp: &i64,
+    creator_token: &Pubkey,
+    start_price: u64,
+    floor_price: u64,
+    auction_duration: i64,
+    bid_gate: Option<BidGate>,
+) -> (InitializeDutchAuctionAccounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let (owner_token_record, _) = find_token_record_account(mint, &creator_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &treasury_token);
+
+    let accounts = InitializeDutchAuctionAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        signer: creator.pubkey(),
+        treasury_mint: *mint,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(), // !!!NOT USED
+        signer_token_account: *creator_token,
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = InitializeDutchAuctionData {
+        app_idx: *timestamp,
+        state_bump: auction_bump,
+        start_price,
+        floor_price,
+        start_at: *timestamp,
+        end_at: *(timestamp) + auction_duration,
+        seller_splits: None,
+        bid_gate,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&creator.pubkey()),
+            &[creator],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_buy_dutch(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    fee_recipient: &Pubkey,
+    gate_accounts: &[Pubkey],
+    creators: &[Pubkey],
+    buyer: &Keypair,
+) -> (BuyDutchAccounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let buyer_token = get_associated_token_address(&buyer.pubkey(), mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &buyer_token);
+
+    let accounts = BuyDutchAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        buyer: buyer.pubkey(),
+        buyer_token_account: buyer_token,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(gate_accounts.iter().map(|account| AccountMeta::new_readonly(*account, false)));
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    let data = BuyDutchData{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_buy_now(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    fee_recipient: &Pubkey,
+    gate_accounts: &[Pubkey],
+    creators: &[Pubkey],
+    buyer: &Keypair,
+) -> (BuyNowAccounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let buyer_token = get_associated_token_address(&buyer.pubkey(), mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &buyer_token);
+
+    let accounts = BuyNowAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        buyer: buyer.pubkey(),
+        buyer_token_account: buyer_token,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(gate_accounts.iter().map(|account| AccountMeta::new_readonly(*account, false)));
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    let data = BuyNowData {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_buy_now3(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    fee_recipient: &Pubkey,
+    gate_accounts: &[Pubkey],
+    creators: &[Pubkey],
+    buyer: &Keypair,
+) -> (BuyNow3Accounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let buyer_token = get_associated_token_address(&buyer.pubkey(), mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &buyer_token);
+
+    let accounts = BuyNow3Accounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        buyer: buyer.pubkey(),
+        buyer_token_account: buyer_token,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(gate_accounts.iter().map(|account| AccountMeta::new_readonly(*account, false)));
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    let data = BuyNow3Data {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_buy_now_english(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    fee_recipient: &Pubkey,
+    gate_accounts: &[Pubkey],
+    creators: &[Pubkey],
+    previous_bidder: Option<&Pubkey>,
+    buyer: &Keypair,
+) -> (BuyNowEnglishAccounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let buyer_token = get_associated_token_address(&buyer.pubkey(), mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &buyer_token);
+    let leader = previous_bidder.copied().unwrap_or_else(system_program::id);
+
+    let accounts = BuyNowEnglishAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        previous_bidder: previous_bidder.copied(),
+        leader_proxy: find_boync_proxy_bid_address(auction, &leader).0,
+        buyer: buyer.pubkey(),
+        buyer_token_account: buyer_token,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(gate_accounts.iter().map(|account| AccountMeta::new_readonly(*account, false)));
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    let data = BuyNowEnglishData {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_claim_dutch(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    winner: &Keypair,
+) -> (ClaimRewardsDutchAccounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let winner_token = get_associated_token_address(&winner.pubkey(), mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &winner_token);
+
+    let accounts = ClaimRewardsDutchAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        winner: winner.pubkey(),
+        winner_token_account: winner_token,
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = ClaimRewardsDutchData{}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&winner.pubkey()),
+            &[winner],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_initialize_sealed(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    auction_bump: u8,
     treasury_token: &Pubkey,
-    bidders_chest: &Pubkey,
     timestamp: &i64,
     creator_token: &Pubkey,
-    auction_duration: Option<&i64>
-) -> (InitializeAuction3Accounts, Transaction) {
+    starting_price: u64,
+    min_deposit: u64,
+    forfeit_bps: u16,
+    commit_duration: i64,
+    reveal_duration: i64,
+) -> (InitializeSealedAuctionAccounts, Transaction) {
     let mint = &digital_asset.mint.pubkey();
 
     let edition = if let Some(edition) = digital_asset.master_edition {
@@ -383,13 +2058,11 @@ pub fn boync_initialize_3(
     let (owner_token_record, _) = find_token_record_account(mint, &creator_token);
     let (destination_token_record, _bump) = find_token_record_account(mint, &treasury_token);
 
-    let accounts = InitializeAuction3Accounts {
+    let accounts = InitializeSealedAuctionAccounts {
         state: *auction,
         treasury: *treasury_token,
-        chest: *bidders_chest,
         signer: creator.pubkey(),
         treasury_mint: *mint,
-        chest_mint: *bid_token_mint,
         metadata: digital_asset.metadata,
         edition,
         owner_token_record,
@@ -406,17 +2079,17 @@ pub fn boync_initialize_3(
     };
     let accounts_meta = accounts.to_account_metas(None);
 
-    let duration = if let Some(duration) = auction_duration {
-        duration
-    } else {
-        &THIRTY_MINUTES_IN_MSEC
-    };
-
-    let data = InitializeAuction3Data {
+    let data = InitializeSealedAuctionData {
         app_idx: *timestamp,
         state_bump: auction_bump,
+        starting_price,
+        min_deposit,
+        forfeit_bps,
         start_at: *timestamp,
-        end_at: *(timestamp) + duration,
+        end_at: *(timestamp) + commit_duration,
+        reveal_end_at: *(timestamp) + commit_duration + reveal_duration,
+        seller_splits: None,
+        bid_gate: None,
     }
     .data();
 
@@ -437,6 +2110,276 @@ pub fn boync_initialize_3(
     )
 }
 
+pub fn boync_commit_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidder: &Keypair,
+    commitment: [u8; 32],
+    deposit: u64,
+) -> (CommitBidAccounts, Transaction) {
+    let accounts = CommitBidAccounts {
+        state: *auction,
+        bidder_state: find_boync_sealed_bid_address(auction, &bidder.pubkey()).0,
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = CommitBidData { commitment, deposit }.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_reveal_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidder: &Keypair,
+    amount: u64,
+    salt: [u8; 32],
+) -> (RevealBidAccounts, Transaction) {
+    let accounts = RevealBidAccounts {
+        state: *auction,
+        bidder_state: find_boync_sealed_bid_address(auction, &bidder.pubkey()).0,
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = RevealBidData { amount, salt }.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_withdraw_sealed_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    authority: &Pubkey,
+    bidder: &Keypair,
+) -> (WithdrawSealedBidAccounts, Transaction) {
+    let accounts = WithdrawSealedBidAccounts {
+        state: *auction,
+        bidder_state: find_boync_sealed_bid_address(auction, &bidder.pubkey()).0,
+        authority: *authority,
+        bidder: bidder.pubkey(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = WithdrawSealedBidData {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_claim_sealed(
+    context: &mut ProgramTestContext,
+    digital_asset: &DigitalAsset,
+    auction: &Pubkey,
+    treasury_token: &Pubkey,
+    authority: &Pubkey,
+    fee_recipient: &Pubkey,
+    creators: &[Pubkey],
+    winner: &Keypair,
+    with_winner_bid: bool,
+) -> (ClaimRewardsSealedAccounts, Transaction) {
+    let mint = &digital_asset.mint.pubkey();
+
+    let edition = if let Some(edition) = digital_asset.master_edition {
+        edition
+    } else {
+        let (edition, _) = find_master_edition_account(mint);
+        edition
+    };
+
+    let winner_token = get_associated_token_address(&winner.pubkey(), mint);
+    let (owner_token_record, _) = find_token_record_account(mint, &treasury_token);
+    let (destination_token_record, _bump) = find_token_record_account(mint, &winner_token);
+
+    let accounts = ClaimRewardsSealedAccounts {
+        state: *auction,
+        treasury: *treasury_token,
+        treasury_mint: *mint,
+        authority: *authority,
+        config: find_boync_config_address().0,
+        fee_recipient: *fee_recipient,
+        winner: winner.pubkey(),
+        winner_token_account: winner_token,
+        winner_bid: with_winner_bid.then(|| find_boync_sealed_bid_address(auction, &winner.pubkey()).0),
+        metadata: digital_asset.metadata,
+        edition,
+        owner_token_record,
+        destination_token_record,
+        auth_rules: mpl_token_auth_rules::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        auth_rules_token_program: mpl_token_auth_rules::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        rent: sysvar::rent::id(),
+        sysvar_instructions: sysvar::instructions::id(),
+    };
+    let mut accounts_meta = accounts.to_account_metas(None);
+    accounts_meta.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    let data = ClaimRewardsSealedData {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&winner.pubkey()),
+            &[winner],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_refund_bid(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    bidders_chest: &Pubkey,
+    bidder_state: &Pubkey,
+    authority: &Pubkey,
+    bidder: &Keypair,
+) -> (RefundBidAccounts, Transaction) {
+    let accounts = RefundBidAccounts {
+        state: *auction,
+        bidders_chest: *bidders_chest,
+        bidder_state: *bidder_state,
+        authority: *authority,
+        bidder: bidder.pubkey(),
+        system_program: system_program::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = RefundBidData {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_migrate_auction2(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    authority: &Keypair,
+) -> (MigrateAuction2Accounts, Transaction) {
+    let accounts = MigrateAuction2Accounts {
+        state: *auction,
+        authority: authority.pubkey(),
+        system_program: system_program::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = MigrateAuction2Data {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn boync_migrate_auction3(
+    context: &mut ProgramTestContext,
+    auction: &Pubkey,
+    authority: &Keypair,
+) -> (MigrateAuction3Accounts, Transaction) {
+    let accounts = MigrateAuction3Accounts {
+        state: *auction,
+        authority: authority.pubkey(),
+        system_program: system_program::id(),
+    };
+    let accounts_meta = accounts.to_account_metas(None);
+
+    let data = MigrateAuction3Data {}.data();
+
+    let instruction = Instruction {
+        program_id: boync_anchor_program::id(),
+        data,
+        accounts: accounts_meta,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
 pub fn find_boync_auction_pdas(
     authority: &Pubkey,
     mint: &Pubkey,